@@ -0,0 +1,249 @@
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+
+use crate::{Euclid, Goniometric};
+
+/// Angle in radians.
+#[derive(
+    Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub struct Rad<T = f32>(pub T);
+
+/// Angle in degrees.
+#[derive(
+    Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub struct Deg<T = f32>(pub T);
+
+impl<T: Goniometric> Goniometric for Rad<T> {
+    type Output = T::Output;
+
+    fn sin(self) -> Self::Output {
+        self.0.sin()
+    }
+
+    fn cos(self) -> Self::Output {
+        self.0.cos()
+    }
+
+    fn atan2(a: Self, b: Self) -> Self::Output {
+        T::atan2(a.0, b.0)
+    }
+}
+
+macro_rules! impl_angle_float {
+    ($($f:ident),*) => {
+        $(
+            impl Rad<$f> {
+                /// Full turn.
+                pub const TURN: Self = Self(core::$f::consts::TAU);
+
+                /// Convert to degrees.
+                pub fn to_deg(self) -> Deg<$f> {
+                    Deg(self.0.to_degrees())
+                }
+
+                /// Get the angle of the vector `(x, y)` from the X axis. The
+                /// result is in range `(-π, π]`.
+                #[cfg(any(feature = "std", feature = "libm"))]
+                pub fn atan2(y: $f, x: $f) -> Self {
+                    Self(<$f as Goniometric>::atan2(y, x))
+                }
+
+                /// Normalize the angle to range `0..2π`.
+                pub fn normalized(self) -> Self {
+                    let r = Euclid::rem_euclid(self.0, Self::TURN.0);
+                    // Small negative values may round up to full turn.
+                    Self(if r < Self::TURN.0 { r } else { 0. })
+                }
+
+                /// Normalize the angle to range `(-π, π]`.
+                pub fn normalized_signed(self) -> Self {
+                    let r = self.normalized().0;
+                    Self(if r > Self::TURN.0 / 2. {
+                        r - Self::TURN.0
+                    } else {
+                        r
+                    })
+                }
+
+                /// Get the signed angle by which `self` must be rotated to get
+                /// `other` in the shorter direction. The result is in range
+                /// `(-π, π]`.
+                pub fn shortest_diff(self, other: Self) -> Self {
+                    (other - self).normalized_signed()
+                }
+
+                /// Interpolate between the angles in the shorter direction.
+                /// `0` is `self` and `1` is `other`. The result is not
+                /// normalized.
+                pub fn lerp(self, other: Self, t: $f) -> Self {
+                    self + self.shortest_diff(other) * t
+                }
+            }
+
+            impl Deg<$f> {
+                /// Full turn.
+                pub const TURN: Self = Self(360.);
+
+                /// Convert to radians.
+                pub fn to_rad(self) -> Rad<$f> {
+                    Rad(self.0.to_radians())
+                }
+
+                /// Get the angle of the vector `(x, y)` from the X axis. The
+                /// result is in range `(-180, 180]`.
+                #[cfg(any(feature = "std", feature = "libm"))]
+                pub fn atan2(y: $f, x: $f) -> Self {
+                    Rad::<$f>::atan2(y, x).to_deg()
+                }
+
+                /// Normalize the angle to range `0..360`.
+                pub fn normalized(self) -> Self {
+                    let r = Euclid::rem_euclid(self.0, Self::TURN.0);
+                    // Small negative values may round up to full turn.
+                    Self(if r < Self::TURN.0 { r } else { 0. })
+                }
+
+                /// Normalize the angle to range `(-180, 180]`.
+                pub fn normalized_signed(self) -> Self {
+                    let r = self.normalized().0;
+                    Self(if r > Self::TURN.0 / 2. {
+                        r - Self::TURN.0
+                    } else {
+                        r
+                    })
+                }
+
+                /// Get the signed angle by which `self` must be rotated to get
+                /// `other` in the shorter direction. The result is in range
+                /// `(-180, 180]`.
+                pub fn shortest_diff(self, other: Self) -> Self {
+                    (other - self).normalized_signed()
+                }
+
+                /// Interpolate between the angles in the shorter direction.
+                /// `0` is `self` and `1` is `other`. The result is not
+                /// normalized.
+                pub fn lerp(self, other: Self, t: $f) -> Self {
+                    self + self.shortest_diff(other) * t
+                }
+            }
+
+            impl From<Deg<$f>> for Rad<$f> {
+                fn from(value: Deg<$f>) -> Self {
+                    value.to_rad()
+                }
+            }
+
+            impl From<Rad<$f>> for Deg<$f> {
+                fn from(value: Rad<$f>) -> Self {
+                    value.to_deg()
+                }
+            }
+
+            /// The result of `atan2` is in radians. Use [`Deg::atan2`] to
+            /// get it in degrees.
+            #[cfg(any(feature = "std", feature = "libm"))]
+            impl Goniometric for Deg<$f> {
+                type Output = $f;
+
+                fn sin(self) -> Self::Output {
+                    self.to_rad().sin()
+                }
+
+                fn cos(self) -> Self::Output {
+                    self.to_rad().cos()
+                }
+
+                fn atan2(a: Self, b: Self) -> Self::Output {
+                    <$f as Goniometric>::atan2(a.0, b.0)
+                }
+            }
+        )*
+    };
+}
+
+impl_angle_float!(f32, f64);
+
+macro_rules! impl_angle_ops {
+    ($($t:ident),*) => {
+        $(
+            impl<T: Add> Add for $t<T> {
+                type Output = $t<T::Output>;
+
+                fn add(self, rhs: Self) -> Self::Output {
+                    $t(self.0 + rhs.0)
+                }
+            }
+
+            impl<T: AddAssign> AddAssign for $t<T> {
+                fn add_assign(&mut self, rhs: Self) {
+                    self.0 += rhs.0;
+                }
+            }
+
+            impl<T: Sub> Sub for $t<T> {
+                type Output = $t<T::Output>;
+
+                fn sub(self, rhs: Self) -> Self::Output {
+                    $t(self.0 - rhs.0)
+                }
+            }
+
+            impl<T: SubAssign> SubAssign for $t<T> {
+                fn sub_assign(&mut self, rhs: Self) {
+                    self.0 -= rhs.0;
+                }
+            }
+
+            impl<T: Neg> Neg for $t<T> {
+                type Output = $t<T::Output>;
+
+                fn neg(self) -> Self::Output {
+                    $t(-self.0)
+                }
+            }
+
+            impl<T: Mul> Mul<T> for $t<T> {
+                type Output = $t<T::Output>;
+
+                fn mul(self, rhs: T) -> Self::Output {
+                    $t(self.0 * rhs)
+                }
+            }
+
+            impl<T: MulAssign> MulAssign<T> for $t<T> {
+                fn mul_assign(&mut self, rhs: T) {
+                    self.0 *= rhs;
+                }
+            }
+
+            impl<T: Div> Div<T> for $t<T> {
+                type Output = $t<T::Output>;
+
+                fn div(self, rhs: T) -> Self::Output {
+                    $t(self.0 / rhs)
+                }
+            }
+
+            impl<T: DivAssign> DivAssign<T> for $t<T> {
+                fn div_assign(&mut self, rhs: T) {
+                    self.0 /= rhs;
+                }
+            }
+
+            impl<T: Div> Div for $t<T> {
+                type Output = T::Output;
+
+                /// Get the ratio of the two angles.
+                fn div(self, rhs: Self) -> Self::Output {
+                    self.0 / rhs.0
+                }
+            }
+        )*
+    };
+}
+
+impl_angle_ops!(Rad, Deg);
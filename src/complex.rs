@@ -0,0 +1,284 @@
+use core::ops::{
+    Add, AddAssign, Deref, DerefMut, Div, DivAssign, Mul, MulAssign, Neg, Sub,
+    SubAssign,
+};
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::{FloatMath, Goniometric};
+use crate::{One, Vec2, Zero};
+
+/// Complex number. The real part is `x` and the imaginary part is `y` of the
+/// underlying vector.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Complex<T = f32>(pub Vec2<T>);
+
+impl<T> Complex<T> {
+    /// Create complex number from its real and imaginary part.
+    pub const fn new(re: T, im: T) -> Self {
+        Self(Vec2::new(re, im))
+    }
+
+    /// Get the real part.
+    pub fn re(&self) -> T
+    where
+        T: Copy,
+    {
+        self.x
+    }
+
+    /// Get the imaginary part.
+    pub fn im(&self) -> T
+    where
+        T: Copy,
+    {
+        self.y
+    }
+
+    /// Get the complex conjugate.
+    pub fn conj(self) -> Self
+    where
+        T: Neg<Output = T>,
+    {
+        let Vec2 { x, y } = self.0;
+        Self::new(x, -y)
+    }
+
+    /// Get the squared absolute value.
+    pub fn norm_sqr(self) -> T
+    where
+        T: Copy + Add<Output = T> + Mul<Output = T>,
+    {
+        self.x * self.x + self.y * self.y
+    }
+
+    /// Get the multiplicative inverse `1 / self`.
+    pub fn recip(self) -> Self
+    where
+        T: Copy
+            + Add<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>
+            + Neg<Output = T>,
+    {
+        let n = self.norm_sqr();
+        let Vec2 { x, y } = self.0;
+        Self::new(x / n, -y / n)
+    }
+
+    /// Raise to integer power.
+    pub fn powi(self, n: i32) -> Self
+    where
+        T: Copy
+            + Zero
+            + One
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>
+            + Neg<Output = T>,
+    {
+        let mut base = if n < 0 { self.recip() } else { self };
+        let mut exp = n.unsigned_abs();
+        let mut res = Self::ONE;
+        while exp != 0 {
+            if exp & 1 == 1 {
+                res *= base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+        res
+    }
+}
+
+impl<T: Zero + One> Complex<T> {
+    /// The real unit `1`.
+    pub const ONE: Self = Self::new(T::ONE, T::ZERO);
+    /// The imaginary unit `i`.
+    pub const I: Self = Self::new(T::ZERO, T::ONE);
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+macro_rules! impl_complex_float {
+    ($($f:ident),*) => {
+        $(impl Complex<$f> {
+            /// Create complex number from its absolute value and argument.
+            pub fn from_polar<A>(abs: $f, arg: A) -> Self
+            where
+                A: Copy + Goniometric<Output = $f>,
+            {
+                Self(Vec2::from_polar(abs, arg))
+            }
+
+            /// Get the absolute value.
+            pub fn abs(self) -> $f {
+                FloatMath::sqrt(self.norm_sqr())
+            }
+
+            /// Get the argument (angle) in range `(-π, π]`.
+            pub fn arg(self) -> $f {
+                self.0.angle()
+            }
+
+            /// Calculate `e` to the power of this.
+            pub fn exp(self) -> Self {
+                Self::from_polar(FloatMath::exp(self.x), self.y)
+            }
+
+            /// Calculate the principal natural logarithm.
+            pub fn ln(self) -> Self {
+                Self::new(FloatMath::ln(self.abs()), self.arg())
+            }
+
+            /// Raise to real power. This uses the principal value.
+            pub fn powf(self, e: $f) -> Self {
+                if e == 0. {
+                    return Self::ONE;
+                }
+                (self.ln() * e).exp()
+            }
+
+            /// Calculate the principal square root. The real part of the
+            /// result is never negative.
+            pub fn sqrt(self) -> Self {
+                let abs = self.abs();
+                Self::new(
+                    FloatMath::sqrt((abs + self.x) / 2.),
+                    FloatMath::sqrt((abs - self.x) / 2.).copysign(self.y),
+                )
+            }
+
+            /// Iterate over the `n`th roots of unity. The first root is `1`
+            /// and the following are in counterclockwise order.
+            pub fn roots_of_unity(
+                n: u32,
+            ) -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
+                let step = core::$f::consts::TAU / n as $f;
+                (0..n).map(move |k| Self::from_polar(1., step * k as $f))
+            }
+        })*
+    };
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl_complex_float!(f32, f64);
+
+impl<T> Deref for Complex<T> {
+    type Target = Vec2<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Complex<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> From<Vec2<T>> for Complex<T> {
+    fn from(value: Vec2<T>) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> From<(T, T)> for Complex<T> {
+    fn from(value: (T, T)) -> Self {
+        Self(value.into())
+    }
+}
+
+impl<T> From<Complex<T>> for Vec2<T> {
+    fn from(value: Complex<T>) -> Self {
+        value.0
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Complex<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Complex<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Complex<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl<T> Mul for Complex<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (a, b) = (self.0, rhs.0);
+        Self::new(a.x * b.x - a.y * b.y, a.x * b.y + a.y * b.x)
+    }
+}
+
+impl<T> Div for Complex<T>
+where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>,
+{
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let (a, b) = (self.0, rhs.0);
+        let n = rhs.norm_sqr();
+        Self::new((a.x * b.x + a.y * b.y) / n, (a.y * b.x - a.x * b.y) / n)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Complex<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self(self.0 * rhs)
+    }
+}
+
+impl<T: Copy + Div<Output = T>> Div<T> for Complex<T> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Self(self.0 / rhs)
+    }
+}
+
+macro_rules! op_assign {
+    ($op:ident, $fn:ident, $op_assign:ident, $fn_assign:ident) => {
+        impl<R, T> $op_assign<R> for Complex<T>
+        where
+            Self: $op<R, Output = Self> + Copy,
+        {
+            fn $fn_assign(&mut self, rhs: R) {
+                *self = (*self).$fn(rhs);
+            }
+        }
+    };
+}
+
+op_assign!(Add, add, AddAssign, add_assign);
+op_assign!(Sub, sub, SubAssign, sub_assign);
+op_assign!(Mul, mul, MulAssign, mul_assign);
+op_assign!(Div, div, DivAssign, div_assign);
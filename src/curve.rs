@@ -0,0 +1,321 @@
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::marker::PhantomData;
+
+use crate::Vec2;
+
+/// Space filling curve over 2D space with 32 bit coordinates.
+pub trait Curve {
+    /// Get the index of the position on the curve.
+    fn encode(pos: Vec2<u32>) -> u64;
+
+    /// Get the position at the given index on the curve.
+    fn decode(d: u64) -> Vec2<u32>;
+}
+
+/// Morton curve (Z-order). Index is created by interleaving the bits of the
+/// coordinates, x is in the lower bit.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Morton;
+
+/// Hilbert curve. Consecutive positions on the curve are always neighbours
+/// in space.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Hilbert;
+
+impl Curve for Morton {
+    fn encode(pos: Vec2<u32>) -> u64 {
+        spread2(pos.x) | (spread2(pos.y) << 1)
+    }
+
+    fn decode(d: u64) -> Vec2<u32> {
+        Vec2::new(compact2(d), compact2(d >> 1))
+    }
+}
+
+impl Curve for Hilbert {
+    fn encode(pos: Vec2<u32>) -> u64 {
+        let Vec2 { mut x, mut y } = pos;
+        let mut d = 0;
+        let mut s = 1_u32 << 31;
+        while s > 0 {
+            let rx = x & s != 0;
+            let ry = y & s != 0;
+            d += (s as u64) * (s as u64) * ((3 * rx as u64) ^ ry as u64);
+            hilbert_rot(&mut x, &mut y, !0, rx, ry);
+            s >>= 1;
+        }
+        d
+    }
+
+    fn decode(mut d: u64) -> Vec2<u32> {
+        let mut x = 0;
+        let mut y = 0;
+        let mut s = 1_u64;
+        while s <= 1 << 31 {
+            let rx = d & 2 != 0;
+            let ry = (d ^ rx as u64) & 1 != 0;
+            hilbert_rot(&mut x, &mut y, (s - 1) as u32, rx, ry);
+            x += s as u32 * rx as u32;
+            y += s as u32 * ry as u32;
+            d >>= 2;
+            s <<= 1;
+        }
+        Vec2::new(x, y)
+    }
+}
+
+/// Rotate the quadrant of hilbert curve. `max` is the maximum coordinate in
+/// the quadrant.
+fn hilbert_rot(x: &mut u32, y: &mut u32, max: u32, rx: bool, ry: bool) {
+    if !ry {
+        if rx {
+            *x = max - *x;
+            *y = max - *y;
+        }
+        core::mem::swap(x, y);
+    }
+}
+
+/// Spread the bits so that there is one zero bit between each two bits.
+fn spread2(v: u32) -> u64 {
+    let mut v = v as u64;
+    v = (v | (v << 16)) & 0x0000_ffff_0000_ffff;
+    v = (v | (v << 8)) & 0x00ff_00ff_00ff_00ff;
+    v = (v | (v << 4)) & 0x0f0f_0f0f_0f0f_0f0f;
+    v = (v | (v << 2)) & 0x3333_3333_3333_3333;
+    (v | (v << 1)) & 0x5555_5555_5555_5555
+}
+
+/// Inverse to [`spread2`].
+fn compact2(v: u64) -> u32 {
+    let mut v = v & 0x5555_5555_5555_5555;
+    v = (v | (v >> 1)) & 0x3333_3333_3333_3333;
+    v = (v | (v >> 2)) & 0x0f0f_0f0f_0f0f_0f0f;
+    v = (v | (v >> 4)) & 0x00ff_00ff_00ff_00ff;
+    v = (v | (v >> 8)) & 0x0000_ffff_0000_ffff;
+    (v | (v >> 16)) as u32
+}
+
+/// Spread the bits so that there are two zero bits between each two bits.
+pub(crate) fn spread3(v: u16) -> u64 {
+    let mut v = v as u64;
+    v = (v | (v << 32)) & 0x001f_0000_0000_ffff;
+    v = (v | (v << 16)) & 0x001f_0000_ff00_00ff;
+    v = (v | (v << 8)) & 0x100f_00f0_0f00_f00f;
+    v = (v | (v << 4)) & 0x10c3_0c30_c30c_30c3;
+    (v | (v << 2)) & 0x1249_2492_4924_9249
+}
+
+/// Inverse to [`spread3`].
+pub(crate) fn compact3(v: u64) -> u16 {
+    let mut v = v & 0x1249_2492_4924_9249;
+    v = (v | (v >> 2)) & 0x10c3_0c30_c30c_30c3;
+    v = (v | (v >> 4)) & 0x100f_00f0_0f00_f00f;
+    v = (v | (v >> 8)) & 0x001f_0000_ff00_00ff;
+    v = (v | (v >> 16)) & 0x001f_0000_0000_ffff;
+    (v | (v >> 32)) as u16
+}
+
+/// Spread the bits so that there are two zero bits between each two bits.
+pub(crate) fn spread3_wide(v: u32) -> u128 {
+    (0..32).fold(0, |r, i| r | ((((v >> i) & 1) as u128) << (3 * i)))
+}
+
+/// Inverse to [`spread3_wide`].
+pub(crate) fn compact3_wide(v: u128) -> u32 {
+    (0..32).fold(0, |r, i| r | ((((v >> (3 * i)) & 1) as u32) << i))
+}
+
+/// Iterator over 2D area in the order of the given space filling curve.
+///
+/// The curve is aligned with the top left corner of the area.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct CurveIter<C> {
+    start: Vec2<u32>,
+    size: Vec2<u32>,
+    /// Blocks of the curve that remain to be iterated. Each block is given by
+    /// its first index and by the binary logarithm of its size.
+    stack: Vec<(u64, u32)>,
+    curve: PhantomData<C>,
+}
+
+#[cfg(feature = "alloc")]
+impl<C: Curve> CurveIter<C> {
+    /// Create iterator over the area from `start` (inclusive) to `end`
+    /// (exclusive).
+    pub fn new(start: Vec2<u32>, end: Vec2<u32>) -> Self {
+        let size = Vec2::new(
+            end.x.saturating_sub(start.x),
+            end.y.saturating_sub(start.y),
+        );
+        let mut stack = vec![];
+        if size.x != 0 && size.y != 0 {
+            let side = *size.max() - 1;
+            stack.push((0, u32::BITS - side.leading_zeros()));
+        }
+
+        Self {
+            start,
+            size,
+            stack,
+            curve: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<C: Curve> Iterator for CurveIter<C> {
+    type Item = Vec2<u32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((d, lvl)) = self.stack.pop() {
+            let mask = 1_u32.checked_shl(lvl).map_or(0, |s| !(s - 1));
+            let pos = C::decode(d);
+            let corner = Vec2::new(pos.x & mask, pos.y & mask);
+            if corner.x >= self.size.x || corner.y >= self.size.y {
+                continue;
+            }
+
+            if lvl == 0 {
+                return Some(self.start + pos);
+            }
+
+            let step = 1 << (2 * (lvl - 1));
+            for i in (0..4).rev() {
+                self.stack.push((d + i * step, lvl - 1));
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vec3;
+
+    const EDGES: [u32; 8] = [
+        0,
+        1,
+        2,
+        0x5555_5555,
+        0xaaaa_aaaa,
+        1 << 31,
+        u32::MAX - 1,
+        u32::MAX,
+    ];
+
+    fn neighbours(a: Vec2<u32>, b: Vec2<u32>) -> bool {
+        a.x.abs_diff(b.x) + a.y.abs_diff(b.y) == 1
+    }
+
+    #[test]
+    fn round_trip_2d() {
+        for x in EDGES {
+            for y in EDGES {
+                let p = Vec2::new(x, y);
+                assert_eq!(Vec2::from_morton(p.morton()), p);
+                assert_eq!(Vec2::from_hilbert(p.hilbert()), p);
+            }
+        }
+        assert_eq!(Vec2::new(u32::MAX, u32::MAX).morton(), u64::MAX);
+        assert_eq!(Vec2::new(1, 0).morton(), 1);
+        assert_eq!(Vec2::new(0, 1).morton(), 2);
+        for d in [0, 1, u64::MAX / 3, u64::MAX - 1, u64::MAX] {
+            assert_eq!(Vec2::from_morton(d).morton(), d);
+            assert_eq!(Vec2::from_hilbert(d).hilbert(), d);
+        }
+    }
+
+    #[test]
+    fn round_trip_3d() {
+        let edges16 = [0, 1, 2, 0x5555, 0xaaaa, 1 << 15, u16::MAX];
+        for x in edges16 {
+            for y in edges16 {
+                for z in edges16 {
+                    let p = Vec3::new(x, y, z);
+                    assert_eq!(Vec3::<u16>::from_morton(p.morton()), p);
+                }
+            }
+        }
+        for x in EDGES {
+            for y in EDGES {
+                for z in EDGES {
+                    let p = Vec3::new(x, y, z);
+                    assert_eq!(Vec3::<u32>::from_morton(p.morton()), p);
+                }
+            }
+        }
+
+        let max = Vec3::new(u16::MAX, u16::MAX, u16::MAX);
+        assert_eq!(max.morton(), (1 << 48) - 1);
+        let max = Vec3::new(u32::MAX, u32::MAX, u32::MAX);
+        assert_eq!(max.morton(), (1 << 96) - 1);
+        assert_eq!(Vec3::<u16>::new(1, 0, 0).morton(), 1);
+        assert_eq!(Vec3::<u16>::new(0, 1, 0).morton(), 2);
+        assert_eq!(Vec3::<u32>::new(0, 0, 1).morton(), 4);
+        assert_eq!(Vec3::<u32>::new(2, 0, 0).morton(), 8);
+    }
+
+    #[test]
+    fn hilbert_adjacency() {
+        assert_eq!(Vec2::from_hilbert(0), Vec2::new(0, 0));
+        let starts = [0, 1 << 20, u64::MAX / 3, u64::MAX - 5000];
+        for start in starts {
+            let mut prev = Vec2::from_hilbert(start);
+            for d in start + 1..start + 5000 {
+                let p = Vec2::from_hilbert(d);
+                assert!(neighbours(prev, p), "{d}: {prev:?} {p:?}");
+                prev = p;
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn check_area<C: Curve>(
+        it: CurveIter<C>,
+        start: Vec2<u32>,
+        end: Vec2<u32>,
+    ) -> Vec<Vec2<u32>> {
+        let res: Vec<_> = it.collect();
+        let mut sorted = res.clone();
+        sorted.sort_by_key(|p| (p.y, p.x));
+        let expected: Vec<_> = (start.y..end.y)
+            .flat_map(|y| (start.x..end.x).map(move |x| Vec2::new(x, y)))
+            .collect();
+        assert_eq!(sorted, expected);
+        res
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn curve_iter_covers_area() {
+        let areas = [
+            ((0, 0), (1, 1)),
+            ((3, 5), (10, 8)),
+            ((0, 0), (1, 17)),
+            ((7, 2), (20, 15)),
+            ((u32::MAX - 5, u32::MAX - 3), (u32::MAX, u32::MAX)),
+        ];
+        for (start, end) in areas {
+            let (start, end) = (Vec2::from(start), Vec2::from(end));
+
+            let res = check_area(start.z_order_to(end), start, end);
+            let codes: Vec<_> =
+                res.iter().map(|p| (*p - start).morton()).collect();
+            assert!(codes.is_sorted(), "{start:?} {end:?}");
+
+            let res = check_area(start.hilbert_to(end), start, end);
+            let codes: Vec<_> =
+                res.iter().map(|p| (*p - start).hilbert()).collect();
+            assert!(codes.is_sorted(), "{start:?} {end:?}");
+        }
+
+        assert_eq!(Vec2::new(5, 5).z_order_to((5, 9)).count(), 0);
+        assert_eq!(Vec2::new(5, 5).hilbert_to((2, 9)).count(), 0);
+    }
+}
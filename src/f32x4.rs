@@ -0,0 +1,410 @@
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+use core::{
+    mem,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::FloatMath;
+use crate::{Rgba, Vec4};
+
+/// Four `f32` values that are processed with SIMD instructions. On x86_64
+/// this uses SSE2, on other platforms the operations are scalar.
+///
+/// This is used internally by the `simd_*` methods on [`Vec4<f32>`] and
+/// [`Rgba<f32>`]. The results are the same as with the scalar code
+/// (except for the payload of NaN values).
+#[derive(Copy, Clone)]
+pub(crate) struct F32x4(Repr);
+
+#[cfg(target_arch = "x86_64")]
+type Repr = __m128;
+#[cfg(not(target_arch = "x86_64"))]
+type Repr = [f32; 4];
+
+impl F32x4 {
+    /// Create vector with all the components set to the same value.
+    #[inline]
+    pub(crate) fn splat(v: f32) -> Self {
+        Self::from_array([v; 4])
+    }
+
+    /// Create the vector from array of its components.
+    #[inline]
+    pub(crate) fn from_array(a: [f32; 4]) -> Self {
+        #[cfg(target_arch = "x86_64")]
+        {
+            // SAFETY: `__m128` has the same size as `[f32; 4]` and all bit
+            // patterns are valid for both.
+            Self(unsafe { mem::transmute::<[f32; 4], __m128>(a) })
+        }
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            Self(a)
+        }
+    }
+
+    /// Get the components as array.
+    #[inline]
+    pub(crate) fn to_array(self) -> [f32; 4] {
+        #[cfg(target_arch = "x86_64")]
+        {
+            // SAFETY: `__m128` has the same size as `[f32; 4]` and all bit
+            // patterns are valid for both.
+            unsafe { mem::transmute::<__m128, [f32; 4]>(self.0) }
+        }
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            self.0
+        }
+    }
+
+    /// Calculate the dot product. The products are summed from the first
+    /// to the last as in the scalar code.
+    #[inline]
+    pub(crate) fn dot(self, other: Self) -> f32 {
+        #[cfg(target_arch = "x86_64")]
+        {
+            // SAFETY: SSE2 is always available on x86_64.
+            unsafe {
+                let m = _mm_mul_ps(self.0, other.0);
+                let s = _mm_add_ss(m, _mm_shuffle_ps::<0b01_01_01_01>(m, m));
+                let s = _mm_add_ss(s, _mm_movehl_ps(m, m));
+                let s = _mm_add_ss(s, _mm_shuffle_ps::<0b11_11_11_11>(m, m));
+                _mm_cvtss_f32(s)
+            }
+        }
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            let m = (self * other).0;
+            m[0] + m[1] + m[2] + m[3]
+        }
+    }
+
+    /// Get the componentwise minimum. If any of the two values is NaN, the
+    /// value from `self` is used.
+    #[inline]
+    pub(crate) fn cmin(self, other: Self) -> Self {
+        #[cfg(target_arch = "x86_64")]
+        {
+            // SAFETY: SSE2 is always available on x86_64.
+            Self(unsafe { _mm_min_ps(other.0, self.0) })
+        }
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            self.zip(other, |a, b| if b < a { b } else { a })
+        }
+    }
+
+    /// Get the componentwise maximum. If any of the two values is NaN, the
+    /// value from `self` is used.
+    #[inline]
+    pub(crate) fn cmax(self, other: Self) -> Self {
+        #[cfg(target_arch = "x86_64")]
+        {
+            // SAFETY: SSE2 is always available on x86_64.
+            Self(unsafe { _mm_max_ps(other.0, self.0) })
+        }
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            self.zip(other, |a, b| if b > a { b } else { a })
+        }
+    }
+
+    #[cfg(not(target_arch = "x86_64"))]
+    #[inline]
+    fn zip(self, other: Self, f: impl Fn(f32, f32) -> f32) -> Self {
+        let (a, b) = (self.0, other.0);
+        Self([f(a[0], b[0]), f(a[1], b[1]), f(a[2], b[2]), f(a[3], b[3])])
+    }
+}
+
+impl From<Vec4<f32>> for F32x4 {
+    #[inline]
+    fn from(value: Vec4<f32>) -> Self {
+        Self::from_array(value.into())
+    }
+}
+
+impl From<F32x4> for Vec4<f32> {
+    #[inline]
+    fn from(value: F32x4) -> Self {
+        value.to_array().into()
+    }
+}
+
+impl From<Rgba<f32>> for F32x4 {
+    #[inline]
+    fn from(value: Rgba<f32>) -> Self {
+        value.0.into()
+    }
+}
+
+impl From<F32x4> for Rgba<f32> {
+    #[inline]
+    fn from(value: F32x4) -> Self {
+        Rgba(value.into())
+    }
+}
+
+impl Neg for F32x4 {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        #[cfg(target_arch = "x86_64")]
+        {
+            // Flip only the sign bit as the scalar negation does.
+            // SAFETY: SSE2 is always available on x86_64.
+            Self(unsafe { _mm_xor_ps(self.0, _mm_set1_ps(-0.)) })
+        }
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            Self(self.0.map(|a| -a))
+        }
+    }
+}
+
+macro_rules! impl_op {
+    ($op:ident, $fun:ident, $simd:ident) => {
+        impl $op for F32x4 {
+            type Output = Self;
+
+            #[inline]
+            fn $fun(self, other: Self) -> Self::Output {
+                #[cfg(target_arch = "x86_64")]
+                {
+                    // SAFETY: SSE2 is always available on x86_64.
+                    Self(unsafe { $simd(self.0, other.0) })
+                }
+                #[cfg(not(target_arch = "x86_64"))]
+                {
+                    self.zip(other, |a, b| a.$fun(b))
+                }
+            }
+        }
+
+        impl $op<f32> for F32x4 {
+            type Output = Self;
+
+            #[inline]
+            fn $fun(self, other: f32) -> Self::Output {
+                self.$fun(Self::splat(other))
+            }
+        }
+    };
+}
+
+impl_op!(Add, add, _mm_add_ps);
+impl_op!(Sub, sub, _mm_sub_ps);
+impl_op!(Mul, mul, _mm_mul_ps);
+impl_op!(Div, div, _mm_div_ps);
+
+macro_rules! impl_simd {
+    ($t:ident) => {
+        impl $t<f32> {
+            /// Add the two values componentwise using SIMD.
+            #[inline]
+            pub fn simd_add(self, other: impl Into<Self>) -> Self {
+                (F32x4::from(self) + F32x4::from(other.into())).into()
+            }
+
+            /// Subtract the two values componentwise using SIMD.
+            #[inline]
+            pub fn simd_sub(self, other: impl Into<Self>) -> Self {
+                (F32x4::from(self) - F32x4::from(other.into())).into()
+            }
+
+            /// Multiply the two values componentwise using SIMD.
+            #[inline]
+            pub fn simd_cmul(self, other: impl Into<Self>) -> Self {
+                (F32x4::from(self) * F32x4::from(other.into())).into()
+            }
+
+            /// Divide the two values componentwise using SIMD.
+            #[inline]
+            pub fn simd_cdiv(self, other: impl Into<Self>) -> Self {
+                (F32x4::from(self) / F32x4::from(other.into())).into()
+            }
+
+            /// Multiply all the components by `s` using SIMD.
+            #[inline]
+            pub fn simd_mul(self, s: f32) -> Self {
+                (F32x4::from(self) * s).into()
+            }
+
+            /// Divide all the components by `s` using SIMD.
+            #[inline]
+            pub fn simd_div(self, s: f32) -> Self {
+                (F32x4::from(self) / s).into()
+            }
+
+            /// Negate all the components using SIMD.
+            #[inline]
+            pub fn simd_neg(self) -> Self {
+                (-F32x4::from(self)).into()
+            }
+
+            /// Get the componentwise minimum using SIMD. Same as
+            /// [`CompArithm::cmin`](crate::CompArithm::cmin).
+            #[inline]
+            pub fn simd_cmin(self, other: impl Into<Self>) -> Self {
+                F32x4::from(self).cmin(other.into().into()).into()
+            }
+
+            /// Get the componentwise maximum using SIMD. Same as
+            /// [`CompArithm::cmax`](crate::CompArithm::cmax).
+            #[inline]
+            pub fn simd_cmax(self, other: impl Into<Self>) -> Self {
+                F32x4::from(self).cmax(other.into().into()).into()
+            }
+        }
+    };
+}
+
+impl_simd!(Vec4);
+impl_simd!(Rgba);
+
+impl Vec4<f32> {
+    /// Calculate the dot product using SIMD.
+    #[inline]
+    pub fn simd_dot(self, other: impl Into<Self>) -> f32 {
+        F32x4::from(self).dot(other.into().into())
+    }
+
+    /// Calculate the square of the length of the vector using SIMD.
+    #[inline]
+    pub fn simd_sq_len(self) -> f32 {
+        self.simd_dot(self)
+    }
+
+    /// Calculate the length of the vector using SIMD.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    pub fn simd_len(self) -> f32 {
+        FloatMath::sqrt(self.simd_sq_len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CompArithm, Rgba, Vec4};
+
+    const VALUES: [f32; 14] = [
+        0.,
+        -0.,
+        1.,
+        -1.,
+        0.1,
+        -3.5e10,
+        1e-40,
+        f32::MIN_POSITIVE,
+        f32::MAX,
+        f32::MIN,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        f32::NAN,
+        -f32::NAN,
+    ];
+
+    /// Check that the values are the same. NaNs are equal regardless of
+    /// their payload.
+    fn same(a: f32, b: f32) -> bool {
+        a.to_bits() == b.to_bits() || (a.is_nan() && b.is_nan())
+    }
+
+    fn assert_same(simd: Vec4<f32>, scalar: [f32; 4]) {
+        let s: [f32; 4] = simd.into();
+        assert!(
+            s.iter().zip(scalar).all(|(a, b)| same(*a, b)),
+            "simd: {s:?}, scalar: {scalar:?}"
+        );
+    }
+
+    /// Get all pairs of vectors built from the edge values.
+    fn pairs() -> impl Iterator<Item = ([f32; 4], [f32; 4])> {
+        VALUES.iter().enumerate().flat_map(|(i, _)| {
+            VALUES.iter().enumerate().map(move |(j, _)| {
+                let v = |o: usize| VALUES[(o) % VALUES.len()];
+                (
+                    [v(i), v(i + 1), v(i + 5), v(i + 9)],
+                    [v(j), v(j + 3), v(j + 7), v(j + 2)],
+                )
+            })
+        })
+    }
+
+    fn scalar(
+        a: [f32; 4],
+        b: [f32; 4],
+        f: impl Fn(f32, f32) -> f32,
+    ) -> [f32; 4] {
+        [f(a[0], b[0]), f(a[1], b[1]), f(a[2], b[2]), f(a[3], b[3])]
+    }
+
+    #[test]
+    fn binary_ops() {
+        for (a, b) in pairs() {
+            let (va, vb) = (Vec4::from(a), Vec4::from(b));
+            assert_same(va.simd_add(vb), (va + vb).into());
+            assert_same(va.simd_sub(vb), (va - vb).into());
+            assert_same(va.simd_cmul(vb), va.cmul(vb).into());
+            assert_same(va.simd_cdiv(vb), va.cdiv(vb).into());
+            assert_same(va.simd_cmin(vb), va.cmin(vb).into());
+            assert_same(va.simd_cmax(vb), va.cmax(vb).into());
+
+            let (ra, rb) = (Rgba(va), Rgba(vb));
+            assert_same(ra.simd_add(rb).0, (va + vb).into());
+            assert_same(ra.simd_cmul(rb).0, va.cmul(vb).into());
+            assert_same(ra.simd_cmax(rb).0, va.cmax(vb).into());
+        }
+    }
+
+    #[test]
+    fn scalar_ops() {
+        for (a, _) in pairs() {
+            let va = Vec4::from(a);
+            for s in VALUES {
+                let b = [s; 4];
+                assert_same(va.simd_mul(s), scalar(a, b, |a, b| a * b));
+                assert_same(va.simd_div(s), scalar(a, b, |a, b| a / b));
+                assert_same(Rgba(va).simd_mul(s).0, (va * s).into());
+            }
+            assert_same(va.simd_neg(), a.map(|a| -a));
+            assert_same(Rgba(va).simd_neg().0, (-va).into());
+        }
+    }
+
+    #[test]
+    fn dot() {
+        for (a, b) in pairs() {
+            let (va, vb) = (Vec4::from(a), Vec4::from(b));
+            let (d, s) = (va.simd_dot(vb), va.dot(vb));
+            assert!(same(d, s), "simd: {d}, scalar: {s}");
+
+            let (l, s) = (va.simd_sq_len(), va.sq_len());
+            assert!(same(l, s), "simd: {l}, scalar: {s}");
+        }
+    }
+
+    #[test]
+    fn over() {
+        for (a, b) in pairs() {
+            let (src, dst) = (Rgba(Vec4::from(a)), Rgba(Vec4::from(b)));
+            let res = src.over(dst);
+
+            let sa = a[3];
+            let da = b[3] * (1. - sa);
+            let oa = sa + da;
+            let expected = if oa <= 0. {
+                [0.; 4]
+            } else {
+                let mut c = scalar(a, b, |s, d| (s * sa + d * da) / oa);
+                c[3] = oa;
+                c
+            };
+            assert_same(res.0, expected);
+        }
+    }
+}
@@ -0,0 +1,456 @@
+use core::{
+    fmt::Display,
+    ops::{
+        Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign,
+        Sub, SubAssign,
+    },
+};
+
+use crate::{
+    Cast, Checked, Goniometric, NormalLimits, One, Saturating, Scale, Sqrt,
+    Two, Zero,
+};
+
+/// Fixed point number with `FRAC` fractional bits stored in `i64`.
+///
+/// All the operations (including [`Sqrt`] and [`Goniometric`]) are
+/// calculated only with integers, so the results are the same on all
+/// platforms. `FRAC` must be less than 63, otherwise using the type fails
+/// to compile.
+///
+/// Same as with the primitive integers, arithmetic overflow panics in debug
+/// builds and wraps in release builds.
+#[derive(
+    Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub struct Fixed<const FRAC: u32 = 16>(i64);
+
+/// Fixed point number with 16 fractional bits.
+pub type Q16 = Fixed<16>;
+
+/// Fixed point number with 32 fractional bits.
+pub type Q32 = Fixed<32>;
+
+/// Number of fractional bits used in the goniometric functions.
+const CF: u32 = 48;
+/// Pi with [`CF`] fractional bits.
+const CORDIC_PI: i128 = 884279719003555;
+/// Inverse of the CORDIC gain with [`CF`] fractional bits.
+const CORDIC_K: i128 = 170926505739102;
+/// `atan(2^-i)` with [`CF`] fractional bits. For larger `i` it is `2^-i`.
+const ATAN: [i128; 16] = [
+    221069929750889,
+    130505199945453,
+    68955363498242,
+    35002819193903,
+    17569333089919,
+    8793231387230,
+    4397688649582,
+    2198978517948,
+    1099506035422,
+    549755114839,
+    274877819563,
+    137438942549,
+    68719475371,
+    34359738197,
+    17179869163,
+    8589934589,
+];
+
+impl<const FRAC: u32> Fixed<FRAC> {
+    /// Compile time check of the number of fractional bits.
+    const CHECK: () = assert!(FRAC < 63, "FRAC must be less than 63");
+
+    /// The smallest representable value.
+    pub const MIN: Self = Self::from_raw(i64::MIN);
+    /// The largest representable value.
+    pub const MAX: Self = Self::from_raw(i64::MAX);
+    /// The smallest positive value.
+    pub const EPSILON: Self = Self::from_raw(1);
+    /// The value of pi.
+    pub const PI: Self = Self::from_raw(rescale(CORDIC_PI, CF, FRAC) as i64);
+
+    /// Create fixed point number from its raw representation.
+    pub const fn from_raw(raw: i64) -> Self {
+        let () = Self::CHECK;
+        Self(raw)
+    }
+
+    /// Get the raw representation of the number.
+    pub const fn raw(self) -> i64 {
+        self.0
+    }
+
+    /// Create fixed point number from integer. Ignore potential overflows.
+    pub const fn from_int(v: i64) -> Self {
+        Self::from_raw(v.wrapping_shl(FRAC))
+    }
+
+    /// Get the integer part of the number (rounded towards negative
+    /// infinity).
+    pub const fn floor_int(self) -> i64 {
+        self.0 >> FRAC
+    }
+
+    /// Create fixed point number from float. Values out of range saturate.
+    pub fn from_f64(v: f64) -> Self {
+        let () = Self::CHECK;
+        Self((v * (1_u64 << FRAC) as f64) as i64)
+    }
+
+    /// Convert the number to float.
+    pub fn to_f64(self) -> f64 {
+        self.0 as f64 / (1_u64 << FRAC) as f64
+    }
+}
+
+impl<const FRAC: u32> Zero for Fixed<FRAC> {
+    const ZERO: Self = Self::from_raw(0);
+}
+
+impl<const FRAC: u32> One for Fixed<FRAC> {
+    const ONE: Self = Self::from_raw(1 << FRAC);
+}
+
+impl<const FRAC: u32> Two for Fixed<FRAC> {
+    const TWO: Self = Self::from_raw(2 << FRAC);
+}
+
+impl<const FRAC: u32> NormalLimits for Fixed<FRAC> {
+    const NORM_MAX: Self = Self::ONE;
+    const NORM_MIN: Self = Self::ZERO;
+}
+
+impl<const FRAC: u32> Sqrt for Fixed<FRAC> {
+    type Output = Self;
+
+    /// Calculate the square root. Square root of negative number is zero.
+    fn sqrt(self) -> Self::Output {
+        if self.0 <= 0 {
+            return Self::ZERO;
+        }
+        Self((((self.0 as u128) << FRAC).isqrt()) as i64)
+    }
+}
+
+impl<const FRAC: u32> Goniometric for Fixed<FRAC> {
+    type Output = Self;
+
+    fn sin(self) -> Self::Output {
+        let (_, sin) = sin_cos(rescale(self.0 as i128, FRAC, CF));
+        Self(from_cordic(sin, FRAC) as i64)
+    }
+
+    fn cos(self) -> Self::Output {
+        let (cos, _) = sin_cos(rescale(self.0 as i128, FRAC, CF));
+        Self(from_cordic(cos, FRAC) as i64)
+    }
+
+    fn atan2(a: Self, b: Self) -> Self::Output {
+        Self(from_cordic(atan2(a.0 as i128, b.0 as i128), FRAC) as i64)
+    }
+}
+
+impl<const FRAC: u32> Checked for Fixed<FRAC> {
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        self.0.checked_sub(other.0).map(Self)
+    }
+
+    fn checked_add(self, other: Self) -> Option<Self> {
+        self.0.checked_add(other.0).map(Self)
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        i64::try_from(mul_raw(self.0, other.0, FRAC)).ok().map(Self)
+    }
+}
+
+impl<const FRAC: u32> Saturating for Fixed<FRAC> {
+    fn saturating_sub(self, other: Self) -> Self {
+        Self(self.0.saturating_sub(other.0))
+    }
+
+    fn saturating_add(self, other: Self) -> Self {
+        Self(self.0.saturating_add(other.0))
+    }
+
+    fn saturating_mul(self, other: Self) -> Self {
+        let res = mul_raw(self.0, other.0, FRAC);
+        Self(res.clamp(i64::MIN as i128, i64::MAX as i128) as i64)
+    }
+}
+
+impl<const A: u32, const B: u32> Cast<Fixed<B>> for Fixed<A> {
+    fn cast(self) -> Fixed<B> {
+        Fixed(rescale(self.0 as i128, A, B) as i64)
+    }
+}
+
+impl<const A: u32, const B: u32> Scale<Fixed<B>> for Fixed<A> {
+    fn scale(self) -> Fixed<B> {
+        self.cast()
+    }
+}
+
+macro_rules! impl_fixed_int {
+    ($($i:ident),*) => {
+        $(
+            impl<const FRAC: u32> Cast<$i> for Fixed<FRAC> {
+                fn cast(self) -> $i {
+                    // Round towards zero as `as` does with floats.
+                    (self.0 / (1 << FRAC)) as $i
+                }
+            }
+
+            impl<const FRAC: u32> Cast<Fixed<FRAC>> for $i {
+                fn cast(self) -> Fixed<FRAC> {
+                    Fixed::from_int(self as i64)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_fixed_float {
+    ($($f:ident),*) => {
+        $(
+            impl<const FRAC: u32> Cast<$f> for Fixed<FRAC> {
+                fn cast(self) -> $f {
+                    self.to_f64() as $f
+                }
+            }
+
+            impl<const FRAC: u32> Cast<Fixed<FRAC>> for $f {
+                fn cast(self) -> Fixed<FRAC> {
+                    Fixed::from_f64(self as f64)
+                }
+            }
+
+            impl<const FRAC: u32> Scale<$f> for Fixed<FRAC> {
+                fn scale(self) -> $f {
+                    self.cast()
+                }
+            }
+
+            impl<const FRAC: u32> Scale<Fixed<FRAC>> for $f {
+                fn scale(self) -> Fixed<FRAC> {
+                    self.cast()
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_fixed_scale_int {
+    ($($i:ident),*) => {
+        $(
+            impl<const FRAC: u32> Scale<$i> for Fixed<FRAC> {
+                fn scale(self) -> $i {
+                    self.to_f64().scale()
+                }
+            }
+
+            impl<const FRAC: u32> Scale<Fixed<FRAC>> for $i {
+                fn scale(self) -> Fixed<FRAC> {
+                    Fixed::from_f64(<$i as Scale<f64>>::scale(self))
+                }
+            }
+        )*
+    };
+}
+
+impl_fixed_int!(
+    u8, i8, u16, i16, u32, i32, u64, i64, usize, isize, u128, i128
+);
+impl_fixed_float!(f32, f64);
+impl_fixed_scale_int!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128);
+
+impl<const FRAC: u32> Add for Fixed<FRAC> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl<const FRAC: u32> Sub for Fixed<FRAC> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl<const FRAC: u32> Mul for Fixed<FRAC> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(narrow(mul_raw(self.0, rhs.0, FRAC)))
+    }
+}
+
+impl<const FRAC: u32> Div for Fixed<FRAC> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Self(narrow(((self.0 as i128) << FRAC) / rhs.0 as i128))
+    }
+}
+
+impl<const FRAC: u32> Rem for Fixed<FRAC> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        Self(self.0 % rhs.0)
+    }
+}
+
+impl<const FRAC: u32> Neg for Fixed<FRAC> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+macro_rules! impl_op_assign {
+    ($($tr:ident, $fun:ident, $op:tt);* $(;)?) => {
+        $(impl<const FRAC: u32> $tr for Fixed<FRAC> {
+            fn $fun(&mut self, rhs: Self) {
+                *self = *self $op rhs;
+            }
+        })*
+    };
+}
+
+impl_op_assign!(
+    AddAssign, add_assign, +;
+    SubAssign, sub_assign, -;
+    MulAssign, mul_assign, *;
+    DivAssign, div_assign, /;
+    RemAssign, rem_assign, %;
+);
+
+impl<const FRAC: u32> Display for Fixed<FRAC> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.to_f64().fmt(f)
+    }
+}
+
+/// Multiply two raw fixed point values.
+fn mul_raw(a: i64, b: i64, frac: u32) -> i128 {
+    (a as i128 * b as i128) >> frac
+}
+
+/// Convert result of operation to raw fixed point value. Same as with the
+/// primitive integers, overflow panics in debug builds and wraps in release
+/// builds.
+fn narrow(v: i128) -> i64 {
+    if cfg!(debug_assertions) {
+        i64::try_from(v).expect("Fixed overflow")
+    } else {
+        v as i64
+    }
+}
+
+/// Change the number of fractional bits of raw fixed point value.
+const fn rescale(v: i128, from: u32, to: u32) -> i128 {
+    if from < to {
+        v << (to - from)
+    } else {
+        v >> (from - to)
+    }
+}
+
+/// Convert raw value with [`CF`] fractional bits to `to` fractional bits.
+/// Round to nearest so that the CORDIC error doesn't show in the result.
+fn from_cordic(v: i128, to: u32) -> i128 {
+    if to < CF {
+        rescale(v + (1 << (CF - to - 1)), CF, to)
+    } else {
+        rescale(v, CF, to)
+    }
+}
+
+fn atan_step(i: u32) -> i128 {
+    if (i as usize) < ATAN.len() {
+        ATAN[i as usize]
+    } else {
+        1 << (CF - i)
+    }
+}
+
+/// Calculate cosine and sine of the angle using CORDIC. All the values have
+/// [`CF`] fractional bits.
+fn sin_cos(angle: i128) -> (i128, i128) {
+    let mut z = angle.rem_euclid(2 * CORDIC_PI);
+    if z > CORDIC_PI {
+        z -= 2 * CORDIC_PI;
+    }
+
+    // CORDIC converges only for angles in range -pi/2..=pi/2.
+    let mut neg = true;
+    if z > CORDIC_PI / 2 {
+        z -= CORDIC_PI;
+    } else if z < -CORDIC_PI / 2 {
+        z += CORDIC_PI;
+    } else {
+        neg = false;
+    }
+
+    if z == 0 {
+        let one = 1 << CF;
+        return if neg { (-one, 0) } else { (one, 0) };
+    }
+
+    let (mut x, mut y) = (CORDIC_K, 0);
+    for i in 0..CF {
+        let (dx, dy) = (y >> i, x >> i);
+        if z >= 0 {
+            x -= dx;
+            y += dy;
+            z -= atan_step(i);
+        } else {
+            x += dx;
+            y -= dy;
+            z += atan_step(i);
+        }
+    }
+
+    if neg { (-x, -y) } else { (x, y) }
+}
+
+/// Calculate `atan(y / x)` with correct sign using CORDIC. The result has
+/// [`CF`] fractional bits.
+fn atan2(mut y: i128, mut x: i128) -> i128 {
+    if x == 0 && y == 0 {
+        return 0;
+    }
+
+    // Scale the values up for better precision.
+    let bits = 128 - x.unsigned_abs().max(y.unsigned_abs()).leading_zeros();
+    x <<= 96 - bits;
+    y <<= 96 - bits;
+
+    let mut z = 0;
+    if x < 0 {
+        z = if y >= 0 { CORDIC_PI } else { -CORDIC_PI };
+        x = -x;
+        y = -y;
+    }
+
+    for i in 0..CF {
+        let (dx, dy) = (y >> i, x >> i);
+        if y > 0 {
+            x += dx;
+            y -= dy;
+            z += atan_step(i);
+        } else {
+            x -= dx;
+            y += dy;
+            z -= atan_step(i);
+        }
+    }
+
+    z
+}
@@ -0,0 +1,128 @@
+use alloc::collections::{BTreeSet, VecDeque};
+
+use crate::{Checked, Metric, One, Vec2, Zero};
+
+/// Breadth first iterator over connected positions on 2D grid that satisfy
+/// the predicate.
+///
+/// Positions are produced in order of their distance from the start. The
+/// predicate is called at most once for each position.
+#[derive(Debug, Clone)]
+pub struct FloodFill<T, F> {
+    bounds: Vec2<T>,
+    metric: Metric,
+    pred: F,
+    visited: BTreeSet<(T, T)>,
+    queue: VecDeque<Vec2<T>>,
+}
+
+impl<T, F> FloodFill<T, F>
+where
+    T: Copy + Checked + One + Zero + Ord,
+    F: FnMut(Vec2<T>) -> bool,
+{
+    /// Create flood fill starting at `start` in 2D space of size `bounds`.
+    /// Neighbours are positions at distance 1 with the given `metric`.
+    pub fn new(
+        start: Vec2<T>,
+        bounds: Vec2<T>,
+        metric: Metric,
+        mut pred: F,
+    ) -> Self {
+        let mut visited = BTreeSet::new();
+        let mut queue = VecDeque::new();
+        visited.insert((start.x, start.y));
+        if bounds.size_contains(start) && pred(start) {
+            queue.push_back(start);
+        }
+
+        Self {
+            bounds,
+            metric,
+            pred,
+            visited,
+            queue,
+        }
+    }
+}
+
+impl<T, F> Iterator for FloodFill<T, F>
+where
+    T: Copy + Checked + One + Zero + Ord,
+    F: FnMut(Vec2<T>) -> bool,
+{
+    type Item = Vec2<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let res = self.queue.pop_front()?;
+        for n in res.ring(T::ONE, self.metric, self.bounds) {
+            if self.visited.insert((n.x, n.y)) && (self.pred)(n) {
+                self.queue.push_back(n);
+            }
+        }
+        Some(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn blocked_by_wall() {
+        let wall = |p: Vec2<usize>| p.x != 5;
+        let res: Vec<_> = Vec2::new(0, 0)
+            .flood_fill((10, 10), Metric::Manhattan, wall)
+            .collect();
+        assert_eq!(res.len(), 50);
+        assert!(res.iter().all(|p| p.x < 5));
+
+        // Gap in the wall lets the fill through.
+        let gap = |p: Vec2<usize>| p.x != 5 || p.y == 9;
+        let cnt = Vec2::new(0, 0)
+            .flood_fill((10, 10), Metric::Manhattan, gap)
+            .count();
+        assert_eq!(cnt, 91);
+    }
+
+    #[test]
+    fn diagonal_wall() {
+        // Diagonal wall blocks only the fill that can't move diagonally.
+        let wall = |p: Vec2<u32>| p.x != p.y;
+        let start = Vec2::new(1, 0);
+        let cnt = start.flood_fill((10, 10), Metric::Manhattan, wall).count();
+        assert_eq!(cnt, 45);
+        let cnt = start.flood_fill((10, 10), Metric::Chebyshev, wall).count();
+        assert_eq!(cnt, 90);
+    }
+
+    #[test]
+    fn breadth_first() {
+        let mut called = BTreeSet::new();
+        let start = Vec2::new(4_i32, 6);
+        let res: Vec<_> = start
+            .flood_fill((9, 9), Metric::Chebyshev, |p| {
+                assert!(called.insert((p.x, p.y)), "{p:?} tested twice");
+                true
+            })
+            .collect();
+        assert_eq!(res.len(), 81);
+        let dist =
+            |p: &Vec2<i32>| (p.x - start.x).abs().max((p.y - start.y).abs());
+        assert!(res.windows(2).all(|w| dist(&w[0]) <= dist(&w[1])));
+    }
+
+    #[test]
+    fn invalid_start() {
+        let cnt = Vec2::new(3, 3)
+            .flood_fill((10, 10), Metric::Manhattan, |p| p.x != 3)
+            .count();
+        assert_eq!(cnt, 0);
+        let cnt = Vec2::new(10, 3)
+            .flood_fill((10, 10), Metric::Manhattan, |_| true)
+            .count();
+        assert_eq!(cnt, 0);
+    }
+}
@@ -0,0 +1,19 @@
+use bytemuck::{Pod, Zeroable};
+
+use crate::{Complex, Padding, Rect, Rgba, Vec2, Vec3, Vec4};
+
+macro_rules! impl_pod {
+    ($($t:ident),*) => {
+        $(
+            // SAFETY: The type is `repr(C)` or `repr(transparent)` and it
+            // contains only fields of type `T`, so there is no padding.
+            unsafe impl<T: Zeroable> Zeroable for $t<T> {}
+
+            // SAFETY: The type is `repr(C)` or `repr(transparent)` and it
+            // contains only fields of type `T`, so there is no padding.
+            unsafe impl<T: Pod> Pod for $t<T> {}
+        )*
+    };
+}
+
+impl_pod!(Vec2, Vec3, Vec4, Rect, Padding, Rgba, Complex);
@@ -0,0 +1,59 @@
+use crate::{Vec2, Vec3, Vec4};
+
+macro_rules! impl_glam {
+    ($($t:ident<$v:ty>: $g:ident { $($f:ident),* });* $(;)?) => {
+        $(
+            impl From<glam::$g> for $t<$v> {
+                fn from(value: glam::$g) -> Self {
+                    $t::new($(value.$f),*)
+                }
+            }
+
+            impl From<$t<$v>> for glam::$g {
+                fn from(value: $t<$v>) -> Self {
+                    glam::$g::new($(value.$f),*)
+                }
+            }
+        )*
+    };
+}
+
+impl_glam!(
+    Vec2<f32>: Vec2 { x, y };
+    Vec2<f64>: DVec2 { x, y };
+    Vec2<i8>: I8Vec2 { x, y };
+    Vec2<u8>: U8Vec2 { x, y };
+    Vec2<i16>: I16Vec2 { x, y };
+    Vec2<u16>: U16Vec2 { x, y };
+    Vec2<i32>: IVec2 { x, y };
+    Vec2<u32>: UVec2 { x, y };
+    Vec2<i64>: I64Vec2 { x, y };
+    Vec2<u64>: U64Vec2 { x, y };
+    Vec2<usize>: USizeVec2 { x, y };
+    Vec2<bool>: BVec2 { x, y };
+    Vec3<f32>: Vec3 { x, y, z };
+    Vec3<f32>: Vec3A { x, y, z };
+    Vec3<f64>: DVec3 { x, y, z };
+    Vec3<i8>: I8Vec3 { x, y, z };
+    Vec3<u8>: U8Vec3 { x, y, z };
+    Vec3<i16>: I16Vec3 { x, y, z };
+    Vec3<u16>: U16Vec3 { x, y, z };
+    Vec3<i32>: IVec3 { x, y, z };
+    Vec3<u32>: UVec3 { x, y, z };
+    Vec3<i64>: I64Vec3 { x, y, z };
+    Vec3<u64>: U64Vec3 { x, y, z };
+    Vec3<usize>: USizeVec3 { x, y, z };
+    Vec3<bool>: BVec3 { x, y, z };
+    Vec4<f32>: Vec4 { x, y, z, w };
+    Vec4<f64>: DVec4 { x, y, z, w };
+    Vec4<i8>: I8Vec4 { x, y, z, w };
+    Vec4<u8>: U8Vec4 { x, y, z, w };
+    Vec4<i16>: I16Vec4 { x, y, z, w };
+    Vec4<u16>: U16Vec4 { x, y, z, w };
+    Vec4<i32>: IVec4 { x, y, z, w };
+    Vec4<u32>: UVec4 { x, y, z, w };
+    Vec4<i64>: I64Vec4 { x, y, z, w };
+    Vec4<u64>: U64Vec4 { x, y, z, w };
+    Vec4<usize>: USizeVec4 { x, y, z, w };
+    Vec4<bool>: BVec4 { x, y, z, w };
+);
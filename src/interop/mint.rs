@@ -0,0 +1,41 @@
+use mint::{IntoMint, Point2, Point3, Vector2, Vector3, Vector4};
+
+use crate::{Vec2, Vec3, Vec4};
+
+macro_rules! impl_mint {
+    ($($t:ident: $m:ident { $($f:ident),* }),* $(,)?) => {
+        $(
+            impl<T> From<$m<T>> for $t<T> {
+                fn from(value: $m<T>) -> Self {
+                    Self { $($f: value.$f),* }
+                }
+            }
+
+            impl<T> From<$t<T>> for $m<T> {
+                fn from(value: $t<T>) -> Self {
+                    Self { $($f: value.$f),* }
+                }
+            }
+        )*
+    };
+}
+
+impl_mint!(
+    Vec2: Vector2 { x, y },
+    Vec2: Point2 { x, y },
+    Vec3: Vector3 { x, y, z },
+    Vec3: Point3 { x, y, z },
+    Vec4: Vector4 { x, y, z, w },
+);
+
+impl<T> IntoMint for Vec2<T> {
+    type MintType = Vector2<T>;
+}
+
+impl<T> IntoMint for Vec3<T> {
+    type MintType = Vector3<T>;
+}
+
+impl<T> IntoMint for Vec4<T> {
+    type MintType = Vector4<T>;
+}
@@ -0,0 +1,8 @@
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "mint")]
+mod mint;
+#[cfg(feature = "rand")]
+mod rand;
@@ -0,0 +1,201 @@
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+use alloc::{vec, vec::Vec};
+use core::ops::{Add, Sub};
+
+use rand::{
+    Rng,
+    distr::{Distribution, StandardUniform, uniform::SampleUniform},
+};
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::FloatMath;
+use crate::{Rect, RectExt, Rgba, Vec2, Vec3, Vec4};
+
+impl<T> Distribution<Vec2<T>> for StandardUniform
+where
+    StandardUniform: Distribution<T>,
+{
+    /// Sample each of the components independently.
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2<T> {
+        Vec2::new(self.sample(rng), self.sample(rng))
+    }
+}
+
+impl<T> Distribution<Vec3<T>> for StandardUniform
+where
+    StandardUniform: Distribution<T>,
+{
+    /// Sample each of the components independently.
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3<T> {
+        Vec3::new(self.sample(rng), self.sample(rng), self.sample(rng))
+    }
+}
+
+impl<T> Distribution<Vec4<T>> for StandardUniform
+where
+    StandardUniform: Distribution<T>,
+{
+    /// Sample each of the components independently.
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec4<T> {
+        Vec4::new(
+            self.sample(rng),
+            self.sample(rng),
+            self.sample(rng),
+            self.sample(rng),
+        )
+    }
+}
+
+impl<T> Distribution<Rgba<T>> for StandardUniform
+where
+    StandardUniform: Distribution<T>,
+{
+    /// Sample each of the components (including alpha) independently.
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Rgba<T> {
+        Rgba(Distribution::<Vec4<T>>::sample(self, rng))
+    }
+}
+
+impl<T> Rect<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + PartialOrd + SampleUniform,
+{
+    /// Get uniformly random point within the rectangle. Panics if the
+    /// rectangle is empty.
+    pub fn sample_point(&self, rng: &mut (impl Rng + ?Sized)) -> Vec2<T> {
+        Vec2::new(
+            rng.random_range(self.left()..self.right()),
+            rng.random_range(self.top()..self.bottom()),
+        )
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+macro_rules! impl_rand_float {
+    ($($f:ident => $tau:expr),*) => {
+        $(
+            impl Vec2<$f> {
+                /// Get uniformly random vector on the unit circle.
+                pub fn random_unit(rng: &mut (impl Rng + ?Sized)) -> Self {
+                    let a: $f = rng.random_range(0. ..$tau);
+                    Self::new(FloatMath::cos(a), FloatMath::sin(a))
+                }
+
+                /// Get uniformly random vector inside the unit disk.
+                pub fn random_in_disk(rng: &mut (impl Rng + ?Sized)) -> Self {
+                    let r = FloatMath::sqrt(rng.random::<$f>());
+                    Self::random_unit(rng) * r
+                }
+            }
+
+            impl Vec3<$f> {
+                /// Get uniformly random vector on the unit sphere.
+                pub fn random_unit(rng: &mut (impl Rng + ?Sized)) -> Self {
+                    let z: $f = rng.random_range(-1. ..=1.);
+                    let r = FloatMath::sqrt(1. - z * z);
+                    let xy = Vec2::<$f>::random_unit(rng) * r;
+                    Self::new(xy.x, xy.y, z)
+                }
+            }
+
+            #[cfg(feature = "alloc")]
+            impl Rect<$f> {
+                /// Get random points within the rectangle such that no two
+                /// points are closer than `min_dist` (Poisson disk sampling).
+                /// The points cover the whole rectangle evenly.
+                ///
+                /// Panics if the number of cells of the internal grid (about
+                /// `2 * area / min_dist²`) doesn't fit into `usize`.
+                pub fn poisson_disk(
+                    &self,
+                    min_dist: $f,
+                    rng: &mut (impl Rng + ?Sized),
+                ) -> Vec<Vec2<$f>> {
+                    // Number of candidates tried around each point.
+                    const TRIES: usize = 30;
+
+                    let mut res = vec![];
+                    if !(self.width() > 0. && self.height() > 0.) {
+                        return res;
+                    }
+                    let d2 = min_dist * min_dist;
+                    if min_dist <= 0. || !(4. * d2).is_finite() {
+                        res.push(self.sample_point(rng));
+                        return res;
+                    }
+
+                    // Each cell may contain at most one point.
+                    let cell = min_dist / core::$f::consts::SQRT_2;
+                    let cols = FloatMath::ceil(self.width() / cell) as usize;
+                    let rows = FloatMath::ceil(self.height() / cell) as usize;
+                    let (cols, rows) = (cols.max(1), rows.max(1));
+                    let cnt =
+                        cols.checked_mul(rows).expect("Too many cells.");
+                    let mut grid = vec![usize::MAX; cnt];
+                    let cell_of = |p: Vec2<$f>| {
+                        let c = ((p.x - self.x) / cell) as usize;
+                        let r = ((p.y - self.y) / cell) as usize;
+                        (c.min(cols - 1), r.min(rows - 1))
+                    };
+
+                    let first = self.sample_point(rng);
+                    let (c, r) = cell_of(first);
+                    grid[r * cols + c] = 0;
+                    res.push(first);
+                    let mut active = vec![0];
+
+                    while !active.is_empty() {
+                        let i = rng.random_range(0..active.len());
+                        let center = res[active[i]];
+                        let mut found = false;
+                        for _ in 0..TRIES {
+                            // Uniformly in the annulus between `min_dist` and
+                            // `2 * min_dist`.
+                            let d = FloatMath::sqrt(
+                                rng.random_range(d2..4. * d2),
+                            );
+                            let p = center + Vec2::<$f>::random_unit(rng) * d;
+                            if p.x < self.left()
+                                || p.x >= self.right()
+                                || p.y < self.top()
+                                || p.y >= self.bottom()
+                            {
+                                continue;
+                            }
+
+                            let (c, r) = cell_of(p);
+                            let near = (r.saturating_sub(2)..(r + 3).min(rows))
+                                .flat_map(|r| {
+                                    (c.saturating_sub(2)..(c + 3).min(cols))
+                                        .map(move |c| r * cols + c)
+                                })
+                                .map(|g| grid[g])
+                                .filter(|j| *j != usize::MAX)
+                                .any(|j| (res[j] - p).sq_len() < d2);
+                            if near {
+                                continue;
+                            }
+
+                            grid[r * cols + c] = res.len();
+                            active.push(res.len());
+                            res.push(p);
+                            found = true;
+                            break;
+                        }
+                        if !found {
+                            active.swap_remove(i);
+                        }
+                    }
+
+                    res
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl_rand_float!(
+    f32 => core::f32::consts::TAU,
+    f64 => core::f64::consts::TAU
+);
@@ -0,0 +1,562 @@
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::{cmp::Ordering, mem, ops::Range};
+
+use crate::TwoComponent;
+
+type Link<T, V> = Option<Box<Node<T, V>>>;
+
+#[derive(Debug, Clone)]
+struct Node<T, V> {
+    range: Range<T>,
+    value: V,
+    /// Maximum end of all the ranges in this subtree.
+    max: T,
+    height: usize,
+    left: Link<T, V>,
+    right: Link<T, V>,
+}
+
+/// Map from ranges to values that can efficiently find all the ranges that
+/// overlap the given range or point.
+///
+/// Ranges are ordered by their start and then by their end. Ranges may
+/// overlap, but each range can be in the map only once. Empty ranges may be
+/// stored, but they never match any query.
+///
+/// The map is a balanced tree, so insertion, removal and lookup take
+/// `O(log n)` time. Queries take `O(min(n, (k + 1) log n))` time where `k`
+/// is the number of the found ranges.
+#[derive(Debug, Clone)]
+pub struct IntervalMap<T, V> {
+    root: Link<T, V>,
+    len: usize,
+}
+
+/// Iterator over ranges in [`IntervalMap`]. The ranges are ordered by their
+/// start and then by their end.
+#[derive(Debug, Clone)]
+pub struct IntervalIter<'a, T, V> {
+    stack: Vec<&'a Node<T, V>>,
+    query: Option<Query<T>>,
+}
+
+/// Ranges that match the query have end larger than `lo` and start smaller
+/// than `hi` (or equal if `inclusive`).
+#[derive(Debug, Copy, Clone)]
+struct Query<T> {
+    lo: T,
+    hi: T,
+    inclusive: bool,
+}
+
+impl<T, V> IntervalMap<T, V> {
+    /// Create empty interval map.
+    pub const fn new() -> Self {
+        Self { root: None, len: 0 }
+    }
+
+    /// Get the number of ranges in the map.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check whether the map is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Remove all the ranges from the map.
+    pub fn clear(&mut self) {
+        self.root = None;
+        self.len = 0;
+    }
+}
+
+impl<T: Copy + PartialOrd, V> IntervalMap<T, V> {
+    /// Insert value for the given range. If the range is already in the
+    /// map, its value is replaced and the old value is returned.
+    pub fn insert(
+        &mut self,
+        range: impl TwoComponent<Val = T>,
+        value: V,
+    ) -> Option<V> {
+        let (start, end) = range.to_components();
+        let mut old = None;
+        self.root =
+            Some(insert(self.root.take(), start..end, value, &mut old));
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+
+    /// Remove the given range from the map. Returns its value if it was in
+    /// the map.
+    pub fn remove(&mut self, range: impl TwoComponent<Val = T>) -> Option<V> {
+        let (start, end) = range.to_components();
+        let mut old = None;
+        self.root = remove(self.root.take(), &(start..end), &mut old);
+        if old.is_some() {
+            self.len -= 1;
+        }
+        old
+    }
+
+    /// Get the value of the given range.
+    pub fn get(&self, range: impl TwoComponent<Val = T>) -> Option<&V> {
+        let (start, end) = range.to_components();
+        let range = start..end;
+        let mut node = self.root.as_deref();
+        while let Some(n) = node {
+            node = match cmp(&range, &n.range) {
+                Ordering::Less => n.left.as_deref(),
+                Ordering::Greater => n.right.as_deref(),
+                Ordering::Equal => return Some(&n.value),
+            };
+        }
+        None
+    }
+
+    /// Get mutable reference to the value of the given range.
+    pub fn get_mut(
+        &mut self,
+        range: impl TwoComponent<Val = T>,
+    ) -> Option<&mut V> {
+        let (start, end) = range.to_components();
+        let range = start..end;
+        let mut node = self.root.as_deref_mut();
+        while let Some(n) = node {
+            node = match cmp(&range, &n.range) {
+                Ordering::Less => n.left.as_deref_mut(),
+                Ordering::Greater => n.right.as_deref_mut(),
+                Ordering::Equal => return Some(&mut n.value),
+            };
+        }
+        None
+    }
+
+    /// Check whether the map contains the given range.
+    pub fn contains_range(&self, range: impl TwoComponent<Val = T>) -> bool {
+        self.get(range).is_some()
+    }
+
+    /// Iterate over all the ranges in the map.
+    pub fn iter(&self) -> IntervalIter<'_, T, V> {
+        IntervalIter::new(self.root.as_deref(), None)
+    }
+
+    /// Iterate over all the ranges that contain the given point.
+    pub fn stab(&self, point: T) -> IntervalIter<'_, T, V> {
+        let query = Query {
+            lo: point,
+            hi: point,
+            inclusive: true,
+        };
+        IntervalIter::new(self.root.as_deref(), Some(query))
+    }
+
+    /// Iterate over all the ranges that overlap with the given range. Empty
+    /// range doesn't overlap with anything.
+    pub fn overlapping(
+        &self,
+        range: impl TwoComponent<Val = T>,
+    ) -> IntervalIter<'_, T, V> {
+        let (lo, hi) = range.to_components();
+        let query = Query {
+            lo,
+            hi,
+            inclusive: false,
+        };
+        let root = if lo < hi { self.root.as_deref() } else { None };
+        IntervalIter::new(root, Some(query))
+    }
+}
+
+impl<T, V> Default for IntervalMap<T, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, V, R> Extend<(R, V)> for IntervalMap<T, V>
+where
+    T: Copy + PartialOrd,
+    R: TwoComponent<Val = T>,
+{
+    fn extend<I: IntoIterator<Item = (R, V)>>(&mut self, iter: I) {
+        for (r, v) in iter {
+            self.insert(r, v);
+        }
+    }
+}
+
+impl<T, V, R> FromIterator<(R, V)> for IntervalMap<T, V>
+where
+    T: Copy + PartialOrd,
+    R: TwoComponent<Val = T>,
+{
+    fn from_iter<I: IntoIterator<Item = (R, V)>>(iter: I) -> Self {
+        let mut res = Self::new();
+        res.extend(iter);
+        res
+    }
+}
+
+impl<'a, T: Copy + PartialOrd, V> IntoIterator for &'a IntervalMap<T, V> {
+    type Item = (&'a Range<T>, &'a V);
+    type IntoIter = IntervalIter<'a, T, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T: Copy + PartialOrd, V> IntervalIter<'a, T, V> {
+    fn new(root: Option<&'a Node<T, V>>, query: Option<Query<T>>) -> Self {
+        let mut res = Self {
+            stack: vec![],
+            query,
+        };
+        res.push_left(root);
+        res
+    }
+
+    /// Push the node and all its left descendants. Subtrees that cannot
+    /// contain matching ranges are skipped.
+    fn push_left(&mut self, mut node: Option<&'a Node<T, V>>) {
+        while let Some(n) = node {
+            if self.query.is_some_and(|q| n.max <= q.lo) {
+                break;
+            }
+            self.stack.push(n);
+            node = n.left.as_deref();
+        }
+    }
+}
+
+impl<'a, T: Copy + PartialOrd, V> Iterator for IntervalIter<'a, T, V> {
+    type Item = (&'a Range<T>, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let n = self.stack.pop()?;
+            let Some(q) = self.query else {
+                self.push_left(n.right.as_deref());
+                return Some((&n.range, &n.value));
+            };
+
+            let start = n.range.start;
+            if start > q.hi || (start == q.hi && !q.inclusive) {
+                // All the remaining ranges start later.
+                self.stack.clear();
+                return None;
+            }
+
+            self.push_left(n.right.as_deref());
+            if n.range.end > q.lo && n.range.start < n.range.end {
+                return Some((&n.range, &n.value));
+            }
+        }
+    }
+}
+
+fn cmp<T: PartialOrd>(a: &Range<T>, b: &Range<T>) -> Ordering {
+    (&a.start, &a.end)
+        .partial_cmp(&(&b.start, &b.end))
+        .unwrap_or(Ordering::Equal)
+}
+
+fn height<T, V>(link: &Link<T, V>) -> usize {
+    link.as_ref().map_or(0, |n| n.height)
+}
+
+impl<T: Copy + PartialOrd, V> Node<T, V> {
+    fn new(range: Range<T>, value: V) -> Self {
+        Self {
+            max: range.end,
+            range,
+            value,
+            height: 1,
+            left: None,
+            right: None,
+        }
+    }
+
+    /// Recalculate the height and max from the children.
+    fn update(&mut self) {
+        self.height = height(&self.left).max(height(&self.right)) + 1;
+        self.max = self.range.end;
+        for c in [&self.left, &self.right].into_iter().flatten() {
+            if c.max > self.max {
+                self.max = c.max;
+            }
+        }
+    }
+}
+
+fn rotate_right<T: Copy + PartialOrd, V>(
+    mut n: Box<Node<T, V>>,
+) -> Box<Node<T, V>> {
+    let mut l = n.left.take().unwrap();
+    n.left = l.right.take();
+    n.update();
+    l.right = Some(n);
+    l.update();
+    l
+}
+
+fn rotate_left<T: Copy + PartialOrd, V>(
+    mut n: Box<Node<T, V>>,
+) -> Box<Node<T, V>> {
+    let mut r = n.right.take().unwrap();
+    n.right = r.left.take();
+    n.update();
+    r.left = Some(n);
+    r.update();
+    r
+}
+
+fn balance<T: Copy + PartialOrd, V>(
+    mut n: Box<Node<T, V>>,
+) -> Box<Node<T, V>> {
+    n.update();
+    let (l, r) = (height(&n.left), height(&n.right));
+    if l > r + 1 {
+        let left = n.left.take().unwrap();
+        n.left = Some(if height(&left.left) < height(&left.right) {
+            rotate_left(left)
+        } else {
+            left
+        });
+        rotate_right(n)
+    } else if r > l + 1 {
+        let right = n.right.take().unwrap();
+        n.right = Some(if height(&right.right) < height(&right.left) {
+            rotate_right(right)
+        } else {
+            right
+        });
+        rotate_left(n)
+    } else {
+        n
+    }
+}
+
+fn insert<T: Copy + PartialOrd, V>(
+    link: Link<T, V>,
+    range: Range<T>,
+    value: V,
+    old: &mut Option<V>,
+) -> Box<Node<T, V>> {
+    let Some(mut n) = link else {
+        return Box::new(Node::new(range, value));
+    };
+
+    match cmp(&range, &n.range) {
+        Ordering::Less => {
+            n.left = Some(insert(n.left.take(), range, value, old))
+        }
+        Ordering::Greater => {
+            n.right = Some(insert(n.right.take(), range, value, old))
+        }
+        Ordering::Equal => {
+            *old = Some(mem::replace(&mut n.value, value));
+            return n;
+        }
+    }
+    balance(n)
+}
+
+fn remove<T: Copy + PartialOrd, V>(
+    link: Link<T, V>,
+    range: &Range<T>,
+    old: &mut Option<V>,
+) -> Link<T, V> {
+    let mut n = link?;
+    match cmp(range, &n.range) {
+        Ordering::Less => n.left = remove(n.left.take(), range, old),
+        Ordering::Greater => n.right = remove(n.right.take(), range, old),
+        Ordering::Equal => {
+            let Node {
+                value, left, right, ..
+            } = *n;
+            *old = Some(value);
+            let Some(right) = right else {
+                return left;
+            };
+            let (mut min, rest) = take_min(right);
+            min.left = left;
+            min.right = rest;
+            return Some(balance(min));
+        }
+    }
+    Some(balance(n))
+}
+
+/// Remove the leftmost node from the subtree. Returns the node and the rest
+/// of the subtree.
+fn take_min<T: Copy + PartialOrd, V>(
+    mut n: Box<Node<T, V>>,
+) -> (Box<Node<T, V>>, Link<T, V>) {
+    match n.left.take() {
+        None => {
+            let rest = n.right.take();
+            (n, rest)
+        }
+        Some(l) => {
+            let (min, rest) = take_min(l);
+            n.left = rest;
+            (min, Some(balance(n)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Simple deterministic pseudo random generator.
+    struct Lcg(u64);
+
+    impl Lcg {
+        fn next(&mut self, max: u32) -> u32 {
+            self.0 = self
+                .0
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((self.0 >> 33) % max as u64) as u32
+        }
+
+        fn range(&mut self) -> Range<u32> {
+            let start = self.next(50);
+            start..start + self.next(10)
+        }
+    }
+
+    /// Check the balance and the max of the subtree. Returns its height.
+    fn check_node(link: &Link<u32, u32>) -> usize {
+        let Some(n) = link else {
+            return 0;
+        };
+        let (l, r) = (check_node(&n.left), check_node(&n.right));
+        assert!(l.abs_diff(r) <= 1, "unbalanced at {:?}", n.range);
+        assert_eq!(n.height, l.max(r) + 1);
+        let max = [&n.left, &n.right]
+            .into_iter()
+            .flatten()
+            .map(|c| c.max)
+            .fold(n.range.end, u32::max);
+        assert_eq!(n.max, max);
+        n.height
+    }
+
+    fn sorted(
+        v: impl IntoIterator<Item = (Range<u32>, u32)>,
+    ) -> Vec<(Range<u32>, u32)> {
+        let mut v: Vec<_> = v.into_iter().collect();
+        v.sort_by_key(|(r, _)| (r.start, r.end));
+        v
+    }
+
+    fn collect<'a>(
+        it: impl Iterator<Item = (&'a Range<u32>, &'a u32)>,
+    ) -> Vec<(Range<u32>, u32)> {
+        it.map(|(r, v)| (r.clone(), *v)).collect()
+    }
+
+    fn check(map: &IntervalMap<u32, u32>, reference: &[(Range<u32>, u32)]) {
+        check_node(&map.root);
+        assert_eq!(map.len(), reference.len());
+        assert_eq!(collect(map.iter()), sorted(reference.iter().cloned()));
+
+        for p in 0..62 {
+            let expected =
+                reference.iter().filter(|(r, _)| r.contains(&p)).cloned();
+            assert_eq!(collect(map.stab(p)), sorted(expected), "stab {p}");
+        }
+
+        for lo in 0..62 {
+            for hi in lo..62 {
+                let expected = reference
+                    .iter()
+                    .filter(|(r, _)| {
+                        lo < hi
+                            && r.start < r.end
+                            && r.start < hi
+                            && lo < r.end
+                    })
+                    .cloned();
+                assert_eq!(
+                    collect(map.overlapping(lo..hi)),
+                    sorted(expected),
+                    "overlapping {lo}..{hi}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn matches_reference() {
+        let mut rng = Lcg(7);
+        let mut map = IntervalMap::new();
+        let mut reference: Vec<(Range<u32>, u32)> = vec![];
+
+        for i in 0..300 {
+            let r = rng.range();
+            let pos = reference.iter().position(|(a, _)| *a == r);
+            if rng.next(3) == 0 {
+                let expected = pos.map(|p| reference.remove(p).1);
+                assert_eq!(map.remove(r.clone()), expected);
+            } else {
+                let expected = match pos {
+                    Some(p) => Some(mem::replace(&mut reference[p].1, i)),
+                    None => {
+                        reference.push((r.clone(), i));
+                        None
+                    }
+                };
+                assert_eq!(map.insert(r.clone(), i), expected);
+            }
+            assert_eq!(
+                map.get(r.clone()),
+                reference.iter().find_map(|(a, v)| (*a == r).then_some(v))
+            );
+            if i % 10 == 0 {
+                check(&map, &reference);
+            }
+        }
+        check(&map, &reference);
+
+        while let Some((r, v)) = reference.pop() {
+            assert_eq!(map.remove(r), Some(v));
+            check_node(&map.root);
+        }
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn sorted_insert_stays_balanced() {
+        let mut map = IntervalMap::new();
+        for i in 0..1000 {
+            map.insert(i..i + 2, i);
+        }
+        assert!(check_node(&map.root) <= 15);
+        for i in (0..1000).step_by(2) {
+            assert_eq!(map.remove(i..i + 2), Some(i));
+        }
+        check_node(&map.root);
+        assert_eq!(map.len(), 500);
+    }
+
+    #[test]
+    fn empty_ranges() {
+        let map: IntervalMap<u32, u32> =
+            [(5..5, 0), (3..7, 1), (5..6, 2)].into_iter().collect();
+        assert_eq!(map.len(), 3);
+        assert_eq!(collect(map.stab(5)), [(3..7, 1), (5..6, 2)]);
+        assert_eq!(collect(map.overlapping(4..6)), [(3..7, 1), (5..6, 2)]);
+        assert_eq!(collect(map.overlapping(5..5)), []);
+        assert_eq!(collect(map.overlapping((6, 4))), []);
+        assert_eq!(map.get(5..5), Some(&0));
+    }
+}
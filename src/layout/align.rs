@@ -0,0 +1,13 @@
+/// Alignment of item within the available space.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum Align {
+    /// Align to the start (left or top).
+    #[default]
+    Start,
+    /// Align to the center.
+    Center,
+    /// Align to the end (right or bottom).
+    End,
+    /// Stretch the item to fill all the available space.
+    Stretch,
+}
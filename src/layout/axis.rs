@@ -0,0 +1,43 @@
+use core::ops::{Index, IndexMut};
+
+use crate::Vec2;
+
+/// Axis in 2D space.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum Axis {
+    /// Horizontal axis. Items along this axis form a row.
+    #[default]
+    X,
+    /// Vertical axis. Items along this axis form a column.
+    Y,
+}
+
+impl Axis {
+    /// Get the other axis.
+    pub fn cross(self) -> Self {
+        match self {
+            Axis::X => Axis::Y,
+            Axis::Y => Axis::X,
+        }
+    }
+}
+
+impl<T> Index<Axis> for Vec2<T> {
+    type Output = T;
+
+    fn index(&self, index: Axis) -> &Self::Output {
+        match index {
+            Axis::X => &self.x,
+            Axis::Y => &self.y,
+        }
+    }
+}
+
+impl<T> IndexMut<Axis> for Vec2<T> {
+    fn index_mut(&mut self, index: Axis) -> &mut Self::Output {
+        match index {
+            Axis::X => &mut self.x,
+            Axis::Y => &mut self.y,
+        }
+    }
+}
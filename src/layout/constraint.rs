@@ -0,0 +1,54 @@
+/// Constraint on size of a single item in [`crate::Layout`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Constraint {
+    /// Exactly the given size.
+    Length(usize),
+    /// Percentage of the available size.
+    Percentage(usize),
+    /// Ratio (first / second) of the available size.
+    Ratio(usize, usize),
+    /// At least the given size. Grows into the remaining space with weight 1.
+    Min(usize),
+    /// At most the given size. Grows into the remaining space with weight 1.
+    Max(usize),
+    /// Grows into the remaining space with the given weight.
+    Fill(usize),
+}
+
+impl Constraint {
+    /// Get the size of the item before the remaining space is distributed.
+    pub(crate) fn base(&self, available: usize) -> usize {
+        match *self {
+            Constraint::Length(l) | Constraint::Min(l) => l,
+            Constraint::Percentage(p) => part(available, p, 100),
+            Constraint::Ratio(a, b) => part(available, a, b),
+            Constraint::Max(_) | Constraint::Fill(_) => 0,
+        }
+    }
+
+    /// Get the weight with which the item grows into the remaining space.
+    pub(crate) fn weight(&self) -> usize {
+        match *self {
+            Constraint::Min(_) | Constraint::Max(_) => 1,
+            Constraint::Fill(w) => w,
+            _ => 0,
+        }
+    }
+
+    /// Get the maximum size of the item.
+    pub(crate) fn max(&self) -> Option<usize> {
+        match *self {
+            Constraint::Max(m) => Some(m),
+            _ => None,
+        }
+    }
+}
+
+/// Get `a / b` of `v` but at most `v`.
+fn part(v: usize, a: usize, b: usize) -> usize {
+    if b == 0 {
+        0
+    } else {
+        (v as u128 * a as u128 / b as u128).min(v as u128) as usize
+    }
+}
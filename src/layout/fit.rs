@@ -0,0 +1,30 @@
+use crate::Vec2;
+
+/// How should size be scaled to fit into rectangle.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum Fit {
+    /// Scale while keeping the aspect ratio so that the whole size is
+    /// visible.
+    #[default]
+    Contain,
+    /// Scale while keeping the aspect ratio so that the whole rectangle is
+    /// covered.
+    Cover,
+    /// Stretch to the size of the rectangle, the aspect ratio is not kept.
+    Fill,
+    /// Same as [`Fit::Contain`], but never scale up.
+    ScaleDown,
+}
+
+impl Fit {
+    /// Get the scale factor for the given size and available space.
+    pub(crate) fn factor(&self, size: Vec2<f64>, space: Vec2<f64>) -> f64 {
+        let x = space.x / size.x;
+        let y = space.y / size.y;
+        match self {
+            Self::Contain | Self::Fill => x.min(y),
+            Self::Cover => x.max(y),
+            Self::ScaleDown => x.min(y).min(1.),
+        }
+    }
+}
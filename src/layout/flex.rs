@@ -0,0 +1,447 @@
+use alloc::{vec, vec::Vec};
+use core::ops::{Add, Sub};
+
+use crate::{
+    Align, Axis, Cast, Justify, MapExt, Padding, Rect, RectExt, Vec2, Zero,
+    layout::snap,
+};
+
+/// Single item in [`Flex`] layout.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FlexItem<T = usize> {
+    /// Base size of the item. On the main axis this is the size before
+    /// growing or shrinking, on the cross axis it is the size if the item is
+    /// not stretched.
+    pub size: Vec2<T>,
+    /// How much the item grows relative to the other items if there is
+    /// remaining space.
+    pub grow: f32,
+    /// How much the item shrinks relative to the other items if there is not
+    /// enough space. This is further scaled by the base size.
+    pub shrink: f32,
+    /// Minimum size of the item.
+    pub min: Vec2<T>,
+    /// Maximum size of the item.
+    pub max: Vec2<Option<T>>,
+    /// Space around the item.
+    pub margin: Padding<T>,
+}
+
+impl<T: Copy + Zero> FlexItem<T> {
+    /// Create item with the given base size that doesn't grow, but can
+    /// shrink.
+    pub fn new(size: impl Into<Vec2<T>>) -> Self {
+        Self {
+            size: size.into(),
+            grow: 0.,
+            shrink: 1.,
+            min: Vec2::ZERO,
+            max: Vec2::new(None, None),
+            margin: Padding::uniform(T::ZERO),
+        }
+    }
+
+    /// Set the grow factor.
+    pub fn grow(mut self, grow: f32) -> Self {
+        self.grow = grow;
+        self
+    }
+
+    /// Set the shrink factor.
+    pub fn shrink(mut self, shrink: f32) -> Self {
+        self.shrink = shrink;
+        self
+    }
+
+    /// Set the minimum size.
+    pub fn min(mut self, min: impl Into<Vec2<T>>) -> Self {
+        self.min = min.into();
+        self
+    }
+
+    /// Set the maximum size.
+    pub fn max(mut self, max: impl Into<Vec2<T>>) -> Self {
+        self.max = max.into().map(Some);
+        self
+    }
+
+    /// Set the margin.
+    pub fn margin(mut self, margin: impl Into<Padding<T>>) -> Self {
+        self.margin = margin.into();
+        self
+    }
+}
+
+/// Flexbox-like layout. Items are placed along the main axis and may wrap
+/// into multiple lines.
+///
+/// Computation is done with floats. For integer types the edges of the
+/// resulting rectangles are rounded, so that neighbouring items don't have
+/// gaps or overlaps.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Flex<T = usize> {
+    /// The main axis. Items are placed along this axis.
+    pub axis: Axis,
+    /// Distribution of the remaining space on the main axis.
+    pub justify: Justify,
+    /// Alignment of items on the cross axis within their line.
+    pub align: Align,
+    /// Whether the items may wrap into multiple lines.
+    pub wrap: bool,
+    /// Gap between items in each of the axes.
+    pub gap: Vec2<T>,
+}
+
+/// Item with resolved sizes along one axis.
+#[derive(Debug, Copy, Clone)]
+struct Resolved {
+    base: f64,
+    target: f64,
+    min: f64,
+    max: f64,
+    margin: (f64, f64),
+    frozen: bool,
+}
+
+impl Resolved {
+    fn new<T>(item: &FlexItem<T>, axis: Axis) -> Self
+    where
+        T: Copy + Cast<f64>,
+    {
+        let min = item.min[axis].cast();
+        let max = item.max[axis].map_or(f64::INFINITY, |m| m.cast());
+        let max = max.max(min);
+        let base = item.size[axis].cast().clamp(min, max);
+        let margin = match axis {
+            Axis::X => (item.margin.left(), item.margin.right()),
+            Axis::Y => (item.margin.top(), item.margin.bottom()),
+        };
+        Self {
+            base,
+            target: base,
+            min,
+            max,
+            margin: margin.map(|a| a.cast()),
+            frozen: false,
+        }
+    }
+
+    fn outer(&self) -> f64 {
+        self.target + self.margin.0 + self.margin.1
+    }
+}
+
+impl<T: Zero> Flex<T> {
+    /// Create flex layout with the given main axis.
+    pub fn new(axis: Axis) -> Self {
+        Self {
+            axis,
+            justify: Justify::Start,
+            align: Align::Start,
+            wrap: false,
+            gap: Vec2::ZERO,
+        }
+    }
+
+    /// Create flex layout where the items are in rows.
+    pub fn row() -> Self {
+        Self::new(Axis::X)
+    }
+
+    /// Create flex layout where the items are in columns.
+    pub fn column() -> Self {
+        Self::new(Axis::Y)
+    }
+
+    /// Set the distribution of the remaining space on the main axis.
+    pub fn justify(mut self, justify: Justify) -> Self {
+        self.justify = justify;
+        self
+    }
+
+    /// Set the alignment on the cross axis.
+    pub fn align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Set whether the items can wrap.
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Set the gap between items.
+    pub fn gap(mut self, gap: impl Into<Vec2<T>>) -> Self {
+        self.gap = gap.into();
+        self
+    }
+}
+
+impl<T> Flex<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + PartialOrd + Cast<f64>,
+    f64: Cast<T>,
+{
+    /// Layout the items in the container. Returns the rectangles of the
+    /// items (without margins) in the same order as the items.
+    pub fn layout(
+        &self,
+        container: impl Into<Rect<T>>,
+        items: &[FlexItem<T>],
+    ) -> Vec<Rect<T>> {
+        let container = container.into();
+        let main = self.axis;
+        let cross = main.cross();
+        let pos = container.pos().cast::<f64>();
+        let size = container.size().cast::<f64>();
+        let gap = self.gap.cast::<f64>();
+
+        let mut mains: Vec<_> =
+            items.iter().map(|i| Resolved::new(i, main)).collect();
+        let mut crosses: Vec<_> =
+            items.iter().map(|i| Resolved::new(i, cross)).collect();
+
+        // Break the items into lines.
+        let mut lines = vec![];
+        let mut start = 0;
+        let mut len = 0.;
+        for (i, m) in mains.iter().enumerate() {
+            let add = if i == start { 0. } else { gap[main] };
+            if self.wrap && i != start && len + add + m.outer() > size[main] {
+                lines.push(start..i);
+                start = i;
+                len = m.outer();
+            } else {
+                len += add + m.outer();
+            }
+        }
+        if start < items.len() {
+            lines.push(start..items.len());
+        }
+
+        let mut res = Vec::with_capacity(items.len());
+        let mut cross_off = pos[cross];
+        for line in lines {
+            let cnt = line.len();
+            let gaps = gap[main] * (cnt - 1) as f64;
+            let ms = &mut mains[line.clone()];
+            let factors: Vec<_> = items[line.clone()]
+                .iter()
+                .map(|i| (i.grow as f64, i.shrink as f64))
+                .collect();
+            flex_line(ms, &factors, size[main] - gaps);
+
+            let line_cross = if self.wrap {
+                crosses[line.clone()]
+                    .iter()
+                    .map(|c| c.outer())
+                    .fold(0., f64::max)
+            } else {
+                size[cross]
+            };
+
+            let used: f64 = ms.iter().map(|m| m.outer()).sum::<f64>() + gaps;
+            let (lead, between) = self.justify.spacing(size[main] - used, cnt);
+            let mut main_off = pos[main] + lead;
+            for (m, c) in ms.iter().zip(&mut crosses[line]) {
+                if self.align == Align::Stretch {
+                    c.target = (line_cross - c.margin.0 - c.margin.1)
+                        .clamp(c.min, c.max);
+                }
+                let free = line_cross - c.outer();
+                let c_start = cross_off
+                    + c.margin.0
+                    + match self.align {
+                        Align::Start | Align::Stretch => 0.,
+                        Align::Center => free / 2.,
+                        Align::End => free,
+                    };
+                let m_start = main_off + m.margin.0;
+
+                let mut start = Vec2::new(0., 0.);
+                start[main] = m_start;
+                start[cross] = c_start;
+                let mut end = start;
+                end[main] += m.target;
+                end[cross] += c.target;
+                let start: Vec2<T> = start.map(snap);
+                let end: Vec2<T> = end.map(snap);
+                res.push(Rect::from_pos_size(start, end - start));
+
+                main_off += m.outer() + gap[main] + between;
+            }
+
+            cross_off += line_cross + gap[cross];
+        }
+
+        res
+    }
+}
+
+/// Resolve the flexible sizes of items on single line so that they fill the
+/// given space.
+fn flex_line(items: &mut [Resolved], factors: &[(f64, f64)], space: f64) {
+    let used: f64 = items.iter().map(|i| i.outer()).sum();
+    let grow = used < space;
+    for (i, (g, s)) in items.iter_mut().zip(factors) {
+        i.frozen = if grow {
+            *g <= 0.
+        } else {
+            *s <= 0. || i.base <= 0.
+        };
+    }
+
+    loop {
+        let used: f64 = items
+            .iter()
+            .map(|i| {
+                (if i.frozen { i.target } else { i.base })
+                    + i.margin.0
+                    + i.margin.1
+            })
+            .sum();
+        let free = space - used;
+
+        let factor = |(i, (g, s)): (&Resolved, &(f64, f64))| {
+            if grow { *g } else { *s * i.base }
+        };
+        let total: f64 = items
+            .iter()
+            .zip(factors)
+            .filter(|(i, _)| !i.frozen)
+            .map(factor)
+            .sum();
+        if total <= 0. {
+            return;
+        }
+
+        let mut violation = 0.;
+        for (i, f) in items.iter_mut().zip(factors) {
+            if i.frozen {
+                continue;
+            }
+            let target = i.base + free * factor((i, f)) / total;
+            i.target = target.clamp(i.min, i.max);
+            violation += i.target - target;
+        }
+
+        for i in items.iter_mut().filter(|i| !i.frozen) {
+            i.frozen = violation == 0.
+                || (violation > 0. && i.target == i.min)
+                || (violation < 0. && i.target == i.max);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn widths(rects: &[Rect]) -> Vec<usize> {
+        rects.iter().map(|r| r.width()).collect()
+    }
+
+    #[test]
+    fn grow() {
+        let items = [
+            FlexItem::new((10, 5)).grow(1.),
+            FlexItem::new((10, 5)).grow(4.),
+            FlexItem::new((10, 5)),
+        ];
+        let res = Flex::row().layout(Rect::new(0, 0, 100, 10), &items);
+        assert_eq!(
+            res,
+            [
+                Rect::new(0, 0, 24, 5),
+                Rect::new(24, 0, 66, 5),
+                Rect::new(90, 0, 10, 5),
+            ]
+        );
+    }
+
+    #[test]
+    fn grow_freezes_at_max() {
+        let items = [
+            FlexItem::new((0, 5)).grow(1.).max((20, 5)),
+            FlexItem::new((0, 5)).grow(1.),
+            FlexItem::new((0, 5)).grow(1.),
+        ];
+        let res = Flex::row().layout(Rect::new(0, 0, 90, 10), &items);
+        assert_eq!(widths(&res), [20, 35, 35]);
+    }
+
+    #[test]
+    fn shrink() {
+        // Shrinking is scaled by the base size.
+        let items = [FlexItem::new((20, 5)), FlexItem::new((60, 5))];
+        let res = Flex::row().layout(Rect::new(0, 0, 60, 10), &items);
+        assert_eq!(widths(&res), [15, 45]);
+
+        let items = [
+            FlexItem::new((50, 5)).min((40, 0)),
+            FlexItem::new((50, 5)),
+            FlexItem::new((10, 5)).shrink(0.),
+        ];
+        let res = Flex::row().layout(Rect::new(0, 0, 70, 10), &items);
+        assert_eq!(widths(&res), [40, 20, 10]);
+    }
+
+    #[test]
+    fn rounding_has_no_gaps() {
+        let items = [FlexItem::new((0, 5)).grow(1.); 3];
+        let res = Flex::row().layout(Rect::new(0, 0, 100, 10), &items);
+        assert_eq!(res[0].left(), 0);
+        assert_eq!(res[0].right(), res[1].left());
+        assert_eq!(res[1].right(), res[2].left());
+        assert_eq!(res[2].right(), 100);
+    }
+
+    #[test]
+    fn wrap() {
+        let items = [
+            FlexItem::new((10, 4)),
+            FlexItem::new((10, 6)),
+            FlexItem::new((10, 3)),
+        ];
+        let flex = Flex::row().wrap(true).gap((5, 2));
+        let res = flex.layout(Rect::new(0, 0, 25, 20), &items);
+        assert_eq!(
+            res,
+            [
+                Rect::new(0, 0, 10, 4),
+                Rect::new(15, 0, 10, 6),
+                Rect::new(0, 8, 10, 3),
+            ]
+        );
+
+        // Without wrapping the items shrink to fit.
+        let res = flex.wrap(false).layout(Rect::new(0, 0, 25, 20), &items);
+        assert_eq!(widths(&res), [5, 5, 5]);
+    }
+
+    #[test]
+    fn align() {
+        let items = [
+            FlexItem::new((10, 6)),
+            FlexItem::new((10, 6)).max((10, 12)),
+            FlexItem::new((10, 6)).margin(Padding::new(0, 1, 0, 3)),
+        ];
+        let container = Rect::new(0, 0, 30, 20);
+
+        let res = Flex::row().align(Align::Stretch).layout(container, &items);
+        assert_eq!(
+            res.iter().map(|r| (r.y(), r.height())).collect::<Vec<_>>(),
+            [(0, 20), (0, 12), (1, 16)]
+        );
+
+        let res = Flex::row().align(Align::Center).layout(container, &items);
+        assert_eq!(
+            res.iter().map(|r| (r.y(), r.height())).collect::<Vec<_>>(),
+            [(7, 6), (7, 6), (6, 6)]
+        );
+
+        let res = Flex::row().align(Align::End).layout(container, &items);
+        assert_eq!(res[0].y(), 14);
+    }
+}
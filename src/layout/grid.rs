@@ -0,0 +1,297 @@
+use alloc::{vec, vec::Vec};
+use core::ops::{Add, Sub};
+
+use crate::{Axis, Cast, MapExt, Rect, RectExt, Vec2, Zero, layout::snap};
+
+/// Definition of size of single row or column in [`Grid`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Track<T = usize> {
+    /// Track with fixed size.
+    Fixed(T),
+    /// Track that takes the given fraction of the remaining space.
+    Fr(f32),
+    /// Track that is large enough to fit the items in it.
+    Auto,
+}
+
+/// Item placed in [`Grid`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct GridItem<T = usize> {
+    /// Column (x) and row (y) of the top left cell of the item. If this is
+    /// [`None`], the item is placed automatically to the first free cell.
+    pub pos: Option<Vec2<usize>>,
+    /// Number of columns (x) and rows (y) that the item spans.
+    pub span: Vec2<usize>,
+    /// Size of the content of the item. This is used to determine the size
+    /// of [`Track::Auto`] tracks.
+    pub size: Vec2<T>,
+}
+
+impl<T> GridItem<T> {
+    /// Create automatically placed item spanning single cell with the given
+    /// content size.
+    pub fn new(size: impl Into<Vec2<T>>) -> Self {
+        Self {
+            pos: None,
+            span: Vec2::new(1, 1),
+            size: size.into(),
+        }
+    }
+
+    /// Set the position of the item. Column is x and row is y.
+    pub fn at(mut self, pos: impl Into<Vec2<usize>>) -> Self {
+        self.pos = Some(pos.into());
+        self
+    }
+
+    /// Set the number of columns (x) and rows (y) that the item spans.
+    pub fn span(mut self, span: impl Into<Vec2<usize>>) -> Self {
+        self.span = span.into();
+        self
+    }
+}
+
+/// Grid layout with fixed, fractional and automatic tracks.
+///
+/// If items don't fit into the given rows or are explicitly placed past
+/// the given columns, new [`Track::Auto`] tracks are added. Computation is
+/// done with floats. For integer types the edges of the resulting
+/// rectangles are rounded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid<T = usize> {
+    /// Definitions of the columns.
+    pub cols: Vec<Track<T>>,
+    /// Definitions of the rows.
+    pub rows: Vec<Track<T>>,
+    /// Gap between columns (x) and rows (y).
+    pub gap: Vec2<T>,
+}
+
+impl<T: Zero> Grid<T> {
+    /// Create grid with the given tracks.
+    pub fn new(
+        cols: impl Into<Vec<Track<T>>>,
+        rows: impl Into<Vec<Track<T>>>,
+    ) -> Self {
+        Self {
+            cols: cols.into(),
+            rows: rows.into(),
+            gap: Vec2::ZERO,
+        }
+    }
+
+    /// Set the gap between columns (x) and rows (y).
+    pub fn gap(mut self, gap: impl Into<Vec2<T>>) -> Self {
+        self.gap = gap.into();
+        self
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + PartialOrd + Cast<f64>,
+    f64: Cast<T>,
+{
+    /// Layout the items in the container. Returns the rectangles of the
+    /// items in the same order as the items.
+    pub fn layout(
+        &self,
+        container: impl Into<Rect<T>>,
+        items: &[GridItem<T>],
+    ) -> Vec<Rect<T>> {
+        let container = container.into();
+        let cells = self.place(items);
+        let pos = container.pos().cast::<f64>();
+        let size = container.size().cast::<f64>();
+        let gap = self.gap.cast::<f64>();
+
+        let cols_cnt = cells
+            .iter()
+            .map(|(p, s)| p.x + s.x)
+            .fold(self.cols.len().max(1), usize::max);
+        let mut cols = self.cols.clone();
+        cols.resize(cols_cnt, Track::Auto);
+        let rows_cnt = cells
+            .iter()
+            .map(|(p, s)| p.y + s.y)
+            .fold(self.rows.len(), usize::max);
+        let mut rows = self.rows.clone();
+        rows.resize(rows_cnt, Track::Auto);
+
+        let offs = Vec2::new(
+            track_offsets(&cols, &cells, items, Axis::X, size.x, gap.x),
+            track_offsets(&rows, &cells, items, Axis::Y, size.y, gap.y),
+        );
+
+        cells
+            .iter()
+            .map(|(p, s)| {
+                let start = Vec2::new(offs.x[p.x].0, offs.y[p.y].0);
+                let end = Vec2::new(
+                    offs.x[p.x + s.x - 1].1,
+                    offs.y[p.y + s.y - 1].1,
+                );
+                let start: Vec2<T> = (pos + start).map(snap);
+                let end: Vec2<T> = (pos + end).map(snap);
+                Rect::from_pos_size(start, end - start)
+            })
+            .collect()
+    }
+
+    /// Get the cell position and span of each item.
+    fn place(&self, items: &[GridItem<T>]) -> Vec<(Vec2<usize>, Vec2<usize>)> {
+        // Explicitly placed items may add columns.
+        let cols = items
+            .iter()
+            .filter_map(|i| i.pos.map(|p| p.x + i.span.x.max(1)))
+            .fold(self.cols.len().max(1), usize::max);
+        let mut used: Vec<Vec<bool>> = vec![];
+        let mut res = vec![(Vec2::new(0, 0), Vec2::new(1, 1)); items.len()];
+
+        let mark = |used: &mut Vec<Vec<bool>>, p: Vec2<usize>, s| {
+            let s: Vec2<usize> = s;
+            if used.len() < p.y + s.y {
+                used.resize(p.y + s.y, vec![false; cols]);
+            }
+            for row in &mut used[p.y..p.y + s.y] {
+                row[p.x..p.x + s.x].fill(true);
+            }
+        };
+
+        for (i, item) in items.iter().enumerate() {
+            if let Some(p) = item.pos {
+                let s = item.span.map(|a| a.max(1));
+                mark(&mut used, p, s);
+                res[i] = (p, s);
+            }
+        }
+
+        for (i, item) in items.iter().enumerate() {
+            if item.pos.is_some() {
+                continue;
+            }
+
+            let s = Vec2::new(item.span.x.clamp(1, cols), item.span.y.max(1));
+            let free = |used: &Vec<Vec<bool>>, p: Vec2<usize>| {
+                (p.y..p.y + s.y).all(|r| {
+                    used.get(r)
+                        .is_none_or(|row| !row[p.x..p.x + s.x].contains(&true))
+                })
+            };
+            let p = (0..)
+                .flat_map(|y| (0..=cols - s.x).map(move |x| Vec2::new(x, y)))
+                .find(|p| free(&used, *p))
+                .unwrap();
+            mark(&mut used, p, s);
+            res[i] = (p, s);
+        }
+
+        res
+    }
+}
+
+/// Get the start and end offsets of the tracks along the given axis.
+fn track_offsets<T>(
+    tracks: &[Track<T>],
+    cells: &[(Vec2<usize>, Vec2<usize>)],
+    items: &[GridItem<T>],
+    axis: Axis,
+    space: f64,
+    gap: f64,
+) -> Vec<(f64, f64)>
+where
+    T: Copy + Cast<f64>,
+{
+    let mut sizes: Vec<f64> = tracks
+        .iter()
+        .map(|t| match t {
+            Track::Fixed(s) => s.cast(),
+            _ => 0.,
+        })
+        .collect();
+
+    // Auto tracks are sized by the items that span only single track first.
+    let mut spanning = vec![];
+    for ((p, s), item) in cells.iter().zip(items) {
+        let (p, s) = (p[axis], s[axis]);
+        let size: f64 = item.size[axis].cast();
+        if s != 1 {
+            spanning.push((p..p + s, size));
+        } else if matches!(tracks[p], Track::Auto) {
+            sizes[p] = sizes[p].max(size);
+        }
+    }
+
+    // The remaining size of spanning items is evenly distributed to the auto
+    // tracks that they span.
+    for (span, size) in spanning {
+        let gaps = gap * (span.len() - 1) as f64;
+        let need = size - gaps - sizes[span.clone()].iter().sum::<f64>();
+        let autos: Vec<_> =
+            span.filter(|i| matches!(tracks[*i], Track::Auto)).collect();
+        if need > 0. && !autos.is_empty() {
+            for i in &autos {
+                sizes[*i] += need / autos.len() as f64;
+            }
+        }
+    }
+
+    let gaps = gap * tracks.len().saturating_sub(1) as f64;
+    let free = space - gaps - sizes.iter().sum::<f64>();
+    let total: f64 = tracks
+        .iter()
+        .map(|t| if let Track::Fr(f) = t { *f as f64 } else { 0. })
+        .sum();
+    if free > 0. && total > 0. {
+        for (s, t) in sizes.iter_mut().zip(tracks) {
+            if let Track::Fr(f) = t {
+                *s = free * *f as f64 / total;
+            }
+        }
+    }
+
+    let mut off = 0.;
+    sizes
+        .into_iter()
+        .map(|s| {
+            let res = (off, off + s);
+            off += s + gap;
+            res
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explicit_past_columns() {
+        let grid = Grid::new([Track::Fixed(10), Track::Fixed(10)], []);
+        let items = [
+            GridItem::new((5, 5)).at((1, 0)),
+            GridItem::new((7, 5)).at((3, 0)),
+            GridItem::new((5, 5)),
+        ];
+        let res = grid.layout(Rect::new(0, 0, 100, 100), &items);
+
+        assert_eq!(res[0], Rect::new(10, 0, 10, 5));
+        // Column 2 is empty auto column and column 3 fits the item.
+        assert_eq!(res[1], Rect::new(20, 0, 7, 5));
+        assert_eq!(res[2], Rect::new(0, 0, 10, 5));
+        assert_eq!(grid.place(&items)[1], ((3, 0).into(), (1, 1).into()));
+    }
+
+    #[test]
+    fn auto_items_fill_grown_columns() {
+        let grid = Grid::new([Track::Fixed(10)], []);
+        let items = [
+            GridItem::new((5, 5)).at((2, 0)),
+            GridItem::new((5, 5)),
+            GridItem::new((5, 5)),
+        ];
+        let cells = grid.place(&items);
+        assert_eq!(cells[1].0, Vec2::new(0, 0));
+        assert_eq!(cells[2].0, Vec2::new(1, 0));
+    }
+}
@@ -0,0 +1,39 @@
+/// Distribution of the remaining space between items along the main axis.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum Justify {
+    /// Items are packed at the start.
+    #[default]
+    Start,
+    /// Items are packed at the center.
+    Center,
+    /// Items are packed at the end.
+    End,
+    /// The first item is at the start, the last item is at the end and the
+    /// remaining space is evenly distributed between the items.
+    SpaceBetween,
+    /// Each item has the same space on both of its sides.
+    SpaceAround,
+    /// The space between any two items and between the items and the edges
+    /// is the same.
+    SpaceEvenly,
+}
+
+#[cfg(feature = "alloc")]
+impl Justify {
+    /// Get the offset of the first item and the additional space between
+    /// items when there are `cnt` items and `free` remaining space.
+    pub(crate) fn spacing(self, free: f64, cnt: usize) -> (f64, f64) {
+        let free = free.max(0.);
+        let n = cnt as f64;
+        match self {
+            Justify::Start => (0., 0.),
+            Justify::Center => (free / 2., 0.),
+            Justify::End => (free, 0.),
+            Justify::SpaceBetween if cnt > 1 => (0., free / (n - 1.)),
+            Justify::SpaceBetween => (0., 0.),
+            Justify::SpaceAround if cnt > 0 => (free / n / 2., free / n),
+            Justify::SpaceEvenly => (free / (n + 1.), free / (n + 1.)),
+            Justify::SpaceAround => (0., 0.),
+        }
+    }
+}
@@ -0,0 +1,67 @@
+mod align;
+mod axis;
+#[cfg(feature = "alloc")]
+mod constraint;
+mod fit;
+#[cfg(feature = "alloc")]
+mod flex;
+#[cfg(feature = "alloc")]
+mod grid;
+mod justify;
+#[cfg(feature = "alloc")]
+mod split;
+
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+use crate::{Cast, FloatMath};
+
+pub use self::{align::*, axis::*, fit::*, justify::*};
+#[cfg(feature = "alloc")]
+pub use self::{constraint::*, flex::*, grid::*, split::*};
+
+/// Convert the float coordinate to the given type. Integer types are rounded
+/// instead of truncated.
+pub(crate) fn snap<T>(v: f64) -> T
+where
+    T: Cast<f64>,
+    f64: Cast<T>,
+{
+    // Integers can't represent the half.
+    let half: T = 0.5.cast();
+    if half.cast() == 0. {
+        FloatMath::round(v).cast()
+    } else {
+        v.cast()
+    }
+}
+
+/// Distribute `amount` proportionally to the weights. Remainders are given to
+/// the items with the largest fractional part, ties are resolved in favor of
+/// the earlier items. The results always sum to `amount` if any of the weights
+/// is nonzero.
+#[cfg(feature = "alloc")]
+pub(crate) fn distribute(amount: usize, weights: &[usize]) -> Vec<usize> {
+    let total: u128 = weights.iter().map(|w| *w as u128).sum();
+    if total == 0 {
+        return vec![0; weights.len()];
+    }
+
+    let mut res = Vec::with_capacity(weights.len());
+    let mut rems = Vec::with_capacity(weights.len());
+    let mut left = amount;
+    for (i, w) in weights.iter().enumerate() {
+        let part = amount as u128 * *w as u128;
+        let v = (part / total) as usize;
+        left -= v;
+        res.push(v);
+        rems.push((part % total, i));
+    }
+
+    rems.sort_by(|(a, ai), (b, bi)| b.cmp(a).then(ai.cmp(bi)));
+    for (_, i) in rems.into_iter().take(left) {
+        res[i] += 1;
+    }
+
+    res
+}
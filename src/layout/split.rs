@@ -0,0 +1,243 @@
+use alloc::vec::Vec;
+use core::ops::{Add, Sub};
+
+use crate::{
+    Axis, Cast, Constraint, MapExt, Padding, Rect, RectExt, Vec2, Zero,
+    layout::distribute,
+};
+
+/// Splits rectangle into rows or columns based on constraints.
+///
+/// Items that have fixed size ([`Constraint::Length`],
+/// [`Constraint::Percentage`], [`Constraint::Ratio`] and [`Constraint::Min`])
+/// get their size first. The remaining space is then distributed between the
+/// items that can grow. If the fixed sizes don't fit, they are shrinked
+/// proportionally. The resulting rectangles never overflow the parent.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Layout {
+    /// The axis along which the rectangle is split.
+    pub axis: Axis,
+    /// Constraints for the individual items.
+    pub constraints: Vec<Constraint>,
+    /// Space between the items.
+    pub spacing: usize,
+    /// Margin around all the items.
+    pub margin: Padding<usize>,
+}
+
+impl Layout {
+    /// Create layout that splits along the given axis.
+    pub fn new(axis: Axis, constraints: impl Into<Vec<Constraint>>) -> Self {
+        Self {
+            axis,
+            constraints: constraints.into(),
+            spacing: 0,
+            margin: Padding::default(),
+        }
+    }
+
+    /// Create layout that splits the rectangle into columns (items are next
+    /// to each other horizontally).
+    pub fn cols(constraints: impl Into<Vec<Constraint>>) -> Self {
+        Self::new(Axis::X, constraints)
+    }
+
+    /// Create layout that splits the rectangle into rows (items are under
+    /// each other).
+    pub fn rows(constraints: impl Into<Vec<Constraint>>) -> Self {
+        Self::new(Axis::Y, constraints)
+    }
+
+    /// Set the space between items.
+    pub fn spacing(mut self, spacing: usize) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Set the margin around all the items.
+    pub fn margin(mut self, margin: impl Into<Padding<usize>>) -> Self {
+        self.margin = margin.into();
+        self
+    }
+
+    /// Get the sizes of the items if the total available size is `len`. This
+    /// doesn't take margin into account.
+    pub fn sizes(&self, len: usize) -> Vec<usize> {
+        let cnt = self.constraints.len();
+        let gaps = self.spacing.saturating_mul(cnt.saturating_sub(1));
+        let available = len.saturating_sub(gaps);
+
+        let mut sizes: Vec<_> =
+            self.constraints.iter().map(|c| c.base(available)).collect();
+        let used = sizes.iter().fold(0_usize, |a, s| a.saturating_add(*s));
+        if used > available {
+            return distribute(available, &sizes);
+        }
+
+        let mut left = available - used;
+        let mut weights: Vec<_> =
+            self.constraints.iter().map(|c| c.weight()).collect();
+        loop {
+            let shares = distribute(left, &weights);
+            let mut capped = false;
+            for (i, c) in self.constraints.iter().enumerate() {
+                let Some(m) = c.max() else {
+                    continue;
+                };
+                if weights[i] != 0 && shares[i] >= m {
+                    sizes[i] = m;
+                    left -= m;
+                    weights[i] = 0;
+                    capped = true;
+                }
+            }
+
+            if !capped {
+                for (s, sh) in sizes.iter_mut().zip(shares) {
+                    *s += sh;
+                }
+                return sizes;
+            }
+        }
+    }
+
+    /// Split the rectangle.
+    pub fn split<T>(&self, rect: impl Into<Rect<T>>) -> Vec<Rect<T>>
+    where
+        T: Copy
+            + Add<Output = T>
+            + Sub<Output = T>
+            + PartialOrd
+            + Zero
+            + Cast<usize>,
+        usize: Cast<T>,
+    {
+        let inner = rect.into().pad_rect(self.margin.cast::<T>());
+        let len: usize = inner.size()[self.axis].cast();
+        let mut off = 0_usize;
+        self.sizes(len)
+            .into_iter()
+            .map(|s| {
+                let start = off.min(len);
+                off = start.saturating_add(s).saturating_add(self.spacing);
+                let mut pos = Vec2::<usize>::ZERO;
+                pos[self.axis] = start;
+                let mut size = inner.size();
+                size[self.axis] = s.min(len - start).cast();
+                Rect::from_pos_size(inner.pos() + pos.cast(), size)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Constraint::*;
+
+    #[test]
+    fn sizes_fill_space() {
+        let l = Layout::cols([Length(10), Fill(1), Min(5), Max(3)]);
+        assert_eq!(l.sizes(100), [10, 41, 46, 3]);
+
+        let l = Layout::cols([Percentage(50), Ratio(1, 4), Fill(2), Fill(1)]);
+        assert_eq!(l.sizes(100), [50, 25, 17, 8]);
+
+        let l = Layout::cols([Length(10), Length(20)]);
+        assert_eq!(l.sizes(100), [10, 20]);
+    }
+
+    #[test]
+    fn max_caps() {
+        let l = Layout::cols([Max(10), Max(20), Fill(1)]);
+        assert_eq!(l.sizes(100), [10, 20, 70]);
+        // Without items that can grow further the space is left unused.
+        let l = Layout::cols([Max(10), Max(20)]);
+        assert_eq!(l.sizes(100), [10, 20]);
+        assert_eq!(l.sizes(20), [10, 10]);
+    }
+
+    #[test]
+    fn over_constrained_shrinks() {
+        let l = Layout::cols([Length(60), Length(60), Fill(1)]);
+        assert_eq!(l.sizes(100), [50, 50, 0]);
+        let l = Layout::cols([Min(30), Length(10), Percentage(80)]);
+        assert_eq!(l.sizes(100), [25, 8, 67]);
+        let l = Layout::cols([Length(10), Length(10)]).spacing(30);
+        assert_eq!(l.sizes(20), [0, 0]);
+    }
+
+    #[test]
+    fn remainder_distribution() {
+        let l = Layout::cols([Fill(1), Fill(1), Fill(1)]);
+        assert_eq!(l.sizes(10), [4, 3, 3]);
+        assert_eq!(l.sizes(11), [4, 4, 3]);
+        let l = Layout::cols([Fill(1), Fill(1)]).spacing(2);
+        assert_eq!(l.sizes(11), [5, 4]);
+        for _ in 0..3 {
+            assert_eq!(Layout::cols([Fill(3), Fill(2)]).sizes(7), [4, 3]);
+        }
+    }
+
+    #[test]
+    fn split_rects() {
+        let l = Layout::rows([Length(2), Fill(1), Length(3)])
+            .spacing(1)
+            .margin(1);
+        let r = l.split(Rect::<u32>::new(0, 0, 10, 20));
+        assert_eq!(
+            r,
+            [
+                Rect::new(1, 1, 8, 2),
+                Rect::new(1, 4, 8, 11),
+                Rect::new(1, 16, 8, 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn never_overflows() {
+        let mut seed = 7_u64;
+        let mut next = |max: u64| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((seed >> 33) % max) as usize
+        };
+
+        for _ in 0..500 {
+            let cnt = next(6);
+            let constraints: Vec<_> =
+                core::iter::repeat_with(|| match next(6) {
+                    0 => Length(next(50)),
+                    1 => Percentage(next(120)),
+                    2 => Ratio(next(4), next(4)),
+                    3 => Min(next(50)),
+                    4 => Max(next(50)),
+                    _ => Fill(next(4)),
+                })
+                .take(cnt)
+                .collect();
+            let l = Layout::cols(constraints.clone()).spacing(next(4));
+            let len = next(120);
+
+            let sizes = l.sizes(len);
+            let gaps = l.spacing * cnt.saturating_sub(1);
+            let available = len.saturating_sub(gaps);
+            let sum: usize = sizes.iter().sum();
+            assert!(sum <= available, "{constraints:?} {len}: {sizes:?}");
+            if constraints.iter().any(|c| matches!(c, Min(_) | Fill(1..))) {
+                assert_eq!(sum, available, "{constraints:?} {len}: {sizes:?}");
+            }
+
+            let parent = Rect::new(3, 5, len, 10);
+            let rects = l.split(parent);
+            for (i, r) in rects.iter().enumerate() {
+                assert!(parent.encloses(r), "{r:?} is outside of {parent:?}");
+                if let Some(n) = rects.get(i + 1) {
+                    assert!(r.right() <= n.left());
+                }
+            }
+        }
+    }
+}
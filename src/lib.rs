@@ -1,18 +1,47 @@
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod angle;
+mod complex;
+mod curve;
+mod f32x4;
+mod fixed;
+#[cfg(feature = "alloc")]
+mod flood_fill;
+mod interop;
+#[cfg(feature = "alloc")]
+mod interval_map;
+mod layout;
+mod noise;
 mod num_traits;
+#[cfg(feature = "alloc")]
+mod packer;
 mod padding;
+#[cfg(feature = "alloc")]
+mod range_set;
 mod rect;
 mod rgba;
 mod traits;
+mod typed;
 mod vec2;
 mod vec2_range_iter;
+mod vec2_ring_iter;
 mod vec3;
 mod vec3_range_iter;
 mod vec4;
 
 pub use self::{
-    num_traits::*, padding::*, rect::*, rgba::*, traits::*, vec2::*,
-    vec2_range_iter::*, vec3::*, vec3_range_iter::*, vec4::*,
+    angle::*, complex::*, curve::*, fixed::*, layout::*, noise::*,
+    num_traits::*, padding::*, rect::*, rgba::*, traits::*, typed::*, vec2::*,
+    vec2_range_iter::*, vec2_ring_iter::*, vec3::*, vec3_range_iter::*,
+    vec4::*,
 };
+#[cfg(feature = "alloc")]
+pub use self::{flood_fill::*, interval_map::*, packer::*, range_set::*};
 
 #[cfg(test)]
 mod tests {}
@@ -0,0 +1,70 @@
+use crate::{Noise, Vec2, Vec3};
+
+/// Fractal Brownian motion. Sums multiple octaves of noise with increasing
+/// frequency and decreasing amplitude. The result is normalized so that it
+/// has the same range as the original noise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fbm<N> {
+    noise: N,
+    octaves: u32,
+    lacunarity: f32,
+    gain: f32,
+}
+
+impl<N> Fbm<N> {
+    /// Create fractal noise from the given noise. By default there are 4
+    /// octaves, lacunarity is 2 and gain is 0.5.
+    pub fn new(noise: N) -> Self {
+        Self {
+            noise,
+            octaves: 4,
+            lacunarity: 2.,
+            gain: 0.5,
+        }
+    }
+
+    /// Set the number of octaves (summed layers of noise).
+    pub fn octaves(mut self, octaves: u32) -> Self {
+        self.octaves = octaves;
+        self
+    }
+
+    /// Set the frequency multiplier between octaves.
+    pub fn lacunarity(mut self, lacunarity: f32) -> Self {
+        self.lacunarity = lacunarity;
+        self
+    }
+
+    /// Set the amplitude multiplier between octaves.
+    pub fn gain(mut self, gain: f32) -> Self {
+        self.gain = gain;
+        self
+    }
+
+    /// Sum the octaves. `f` gets the frequency of the octave.
+    fn sum(&self, mut f: impl FnMut(f32) -> f32) -> f32 {
+        let mut res = 0.;
+        let mut total = 0.;
+        let mut freq = 1.;
+        let mut amp = 1.;
+        for _ in 0..self.octaves {
+            res += amp * f(freq);
+            total += amp;
+            freq *= self.lacunarity;
+            amp *= self.gain;
+        }
+        if total == 0. { 0. } else { res / total }
+    }
+}
+
+impl<N: Noise> Noise for Fbm<N> {
+    fn sample2(&self, pos: impl Into<Vec2<f32>>) -> f32 {
+        let pos = pos.into();
+        self.sum(|f| self.noise.sample2(pos * f))
+    }
+
+    fn sample3(&self, pos: impl Into<Vec3<f32>>) -> f32 {
+        let pos = pos.into();
+        self.sum(|f| self.noise.sample3(pos * f))
+    }
+}
@@ -0,0 +1,105 @@
+mod fbm;
+mod perlin;
+mod simplex;
+mod value;
+mod warp;
+
+use crate::{FloatMath, Vec2, Vec3};
+
+pub use self::{fbm::*, perlin::*, simplex::*, value::*, warp::*};
+
+/// Deterministic noise function over 2D and 3D space. The results are
+/// approximately in range -1..1.
+pub trait Noise {
+    /// Get the value of the noise at the given position in 2D.
+    fn sample2(&self, pos: impl Into<Vec2<f32>>) -> f32;
+
+    /// Get the value of the noise at the given position in 3D.
+    fn sample3(&self, pos: impl Into<Vec3<f32>>) -> f32;
+}
+
+/// Permutation table created from seed. It is used to hash the lattice
+/// points.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct Perm([u8; 256]);
+
+impl Perm {
+    pub(crate) fn new(seed: u64) -> Self {
+        let mut perm = [0; 256];
+        for (i, p) in perm.iter_mut().enumerate() {
+            *p = i as u8;
+        }
+
+        // Fisher-Yates shuffle with splitmix64.
+        let mut state = seed;
+        for i in (1..perm.len()).rev() {
+            state = state.wrapping_add(0x9e3779b97f4a7c15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+            z ^= z >> 31;
+            perm.swap(i, (z % (i as u64 + 1)) as usize);
+        }
+
+        Self(perm)
+    }
+
+    pub(crate) fn hash2(&self, x: i32, y: i32) -> u8 {
+        let a = self.0[(x & 255) as usize] as i32;
+        self.0[(a.wrapping_add(y) & 255) as usize]
+    }
+
+    pub(crate) fn hash3(&self, x: i32, y: i32, z: i32) -> u8 {
+        let a = self.hash2(x, y) as i32;
+        self.0[(a.wrapping_add(z) & 255) as usize]
+    }
+}
+
+/// Split the coordinate to the lattice cell and position within the cell.
+pub(crate) fn split(v: f32) -> (i32, f32) {
+    let f = FloatMath::floor(v);
+    (f as i32, v - f)
+}
+
+/// Smooth interpolation curve with zero first and second derivative at 0
+/// and 1.
+pub(crate) fn fade(t: f32) -> f32 {
+    t * t * t * (t * (t * 6. - 15.) + 10.)
+}
+
+pub(crate) fn lerp(t: f32, a: f32, b: f32) -> f32 {
+    a + t * (b - a)
+}
+
+/// Dot product of the position with one of 8 gradients chosen by the hash.
+pub(crate) fn grad2(h: u8, x: f32, y: f32) -> f32 {
+    match h & 7 {
+        0 => x + y,
+        1 => -x + y,
+        2 => x - y,
+        3 => -x - y,
+        4 => x,
+        5 => -x,
+        6 => y,
+        _ => -y,
+    }
+}
+
+/// Dot product of the position with one of 12 gradients (edges of cube)
+/// chosen by the hash.
+pub(crate) fn grad3(h: u8, x: f32, y: f32, z: f32) -> f32 {
+    match h % 12 {
+        0 => x + y,
+        1 => -x + y,
+        2 => x - y,
+        3 => -x - y,
+        4 => x + z,
+        5 => -x + z,
+        6 => x - z,
+        7 => -x - z,
+        8 => y + z,
+        9 => -y + z,
+        10 => y - z,
+        _ => -y - z,
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{Noise, Vec2, Vec3};
+
+use super::{Perm, fade, grad2, grad3, lerp, split};
+
+/// Perlin gradient noise.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Perlin {
+    perm: Perm,
+}
+
+impl Perlin {
+    /// Create perlin noise with the given seed.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            perm: Perm::new(seed),
+        }
+    }
+}
+
+impl Default for Perlin {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl Noise for Perlin {
+    fn sample2(&self, pos: impl Into<Vec2<f32>>) -> f32 {
+        let pos = pos.into();
+        let (x, fx) = split(pos.x);
+        let (y, fy) = split(pos.y);
+        let (u, v) = (fade(fx), fade(fy));
+        let g = |dx, dy| {
+            let h = self.perm.hash2(x.wrapping_add(dx), y.wrapping_add(dy));
+            grad2(h, fx - dx as f32, fy - dy as f32)
+        };
+
+        lerp(v, lerp(u, g(0, 0), g(1, 0)), lerp(u, g(0, 1), g(1, 1)))
+    }
+
+    fn sample3(&self, pos: impl Into<Vec3<f32>>) -> f32 {
+        let pos = pos.into();
+        let (x, fx) = split(pos.x);
+        let (y, fy) = split(pos.y);
+        let (z, fz) = split(pos.z);
+        let (u, v, w) = (fade(fx), fade(fy), fade(fz));
+        let g = |dx, dy, dz| {
+            let h = self.perm.hash3(
+                x.wrapping_add(dx),
+                y.wrapping_add(dy),
+                z.wrapping_add(dz),
+            );
+            grad3(h, fx - dx as f32, fy - dy as f32, fz - dz as f32)
+        };
+
+        let z0 = lerp(
+            v,
+            lerp(u, g(0, 0, 0), g(1, 0, 0)),
+            lerp(u, g(0, 1, 0), g(1, 1, 0)),
+        );
+        let z1 = lerp(
+            v,
+            lerp(u, g(0, 0, 1), g(1, 0, 1)),
+            lerp(u, g(0, 1, 1), g(1, 1, 1)),
+        );
+        lerp(w, z0, z1)
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{Noise, Vec2, Vec3};
+
+use super::{Perm, grad2, grad3, split};
+
+/// Skew factor for 2D, `(sqrt(3) - 1) / 2`.
+const F2: f32 = 0.366_025_4;
+/// Unskew factor for 2D, `(3 - sqrt(3)) / 6`.
+const G2: f32 = 0.211_324_87;
+/// Skew factor for 3D.
+const F3: f32 = 1. / 3.;
+/// Unskew factor for 3D.
+const G3: f32 = 1. / 6.;
+/// Scale of the 2D result so that it is approximately in range -1..1.
+const SCALE2: f32 = 70.;
+/// Scale of the 3D result so that it is approximately in range -1..1.
+const SCALE3: f32 = 32.;
+
+/// Simplex gradient noise in the style of OpenSimplex. It has less
+/// directional artifacts than [`crate::Perlin`] noise.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Simplex {
+    perm: Perm,
+}
+
+impl Simplex {
+    /// Create simplex noise with the given seed.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            perm: Perm::new(seed),
+        }
+    }
+}
+
+impl Default for Simplex {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl Noise for Simplex {
+    fn sample2(&self, pos: impl Into<Vec2<f32>>) -> f32 {
+        let pos = pos.into();
+        // Find the simplex cell in the skewed space.
+        let s = (pos.x + pos.y) * F2;
+        let (i, _) = split(pos.x + s);
+        let (j, _) = split(pos.y + s);
+        let t = (i as f32 + j as f32) * G2;
+        let x = pos.x - (i as f32 - t);
+        let y = pos.y - (j as f32 - t);
+        let (i1, j1) = if x > y { (1, 0) } else { (0, 1) };
+
+        let corner = |di: i32, dj: i32, x: f32, y: f32| {
+            let t = 0.5 - x * x - y * y;
+            if t <= 0. {
+                return 0.;
+            }
+            let h = self.perm.hash2(i.wrapping_add(di), j.wrapping_add(dj));
+            t * t * t * t * grad2(h, x, y)
+        };
+
+        SCALE2
+            * (corner(0, 0, x, y)
+                + corner(i1, j1, x - i1 as f32 + G2, y - j1 as f32 + G2)
+                + corner(1, 1, x - 1. + 2. * G2, y - 1. + 2. * G2))
+    }
+
+    fn sample3(&self, pos: impl Into<Vec3<f32>>) -> f32 {
+        let pos = pos.into();
+        // Find the simplex cell in the skewed space.
+        let s = (pos.x + pos.y + pos.z) * F3;
+        let (i, _) = split(pos.x + s);
+        let (j, _) = split(pos.y + s);
+        let (k, _) = split(pos.z + s);
+        let t = (i as f32 + j as f32 + k as f32) * G3;
+        let x = pos.x - (i as f32 - t);
+        let y = pos.y - (j as f32 - t);
+        let z = pos.z - (k as f32 - t);
+
+        // Offsets of the second and third corner of the simplex.
+        let (a, b) = if x >= y {
+            if y >= z {
+                ((1, 0, 0), (1, 1, 0))
+            } else if x >= z {
+                ((1, 0, 0), (1, 0, 1))
+            } else {
+                ((0, 0, 1), (1, 0, 1))
+            }
+        } else if y < z {
+            ((0, 0, 1), (0, 1, 1))
+        } else if x < z {
+            ((0, 1, 0), (0, 1, 1))
+        } else {
+            ((0, 1, 0), (1, 1, 0))
+        };
+
+        let corner = |(di, dj, dk): (i32, i32, i32), n: f32| {
+            let x = x - di as f32 + n * G3;
+            let y = y - dj as f32 + n * G3;
+            let z = z - dk as f32 + n * G3;
+            let t = 0.6 - x * x - y * y - z * z;
+            if t <= 0. {
+                return 0.;
+            }
+            let h = self.perm.hash3(
+                i.wrapping_add(di),
+                j.wrapping_add(dj),
+                k.wrapping_add(dk),
+            );
+            t * t * t * t * grad3(h, x, y, z)
+        };
+
+        SCALE3
+            * (corner((0, 0, 0), 0.)
+                + corner(a, 1.)
+                + corner(b, 2.)
+                + corner((1, 1, 1), 3.))
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{Noise, Vec2, Vec3};
+
+use super::{Perm, fade, lerp, split};
+
+/// Value noise. Random values at the lattice points are smoothly
+/// interpolated.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ValueNoise {
+    perm: Perm,
+}
+
+impl ValueNoise {
+    /// Create value noise with the given seed.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            perm: Perm::new(seed),
+        }
+    }
+}
+
+impl Default for ValueNoise {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+/// Convert hash to value in range -1..=1.
+fn value(h: u8) -> f32 {
+    h as f32 / 127.5 - 1.
+}
+
+impl Noise for ValueNoise {
+    fn sample2(&self, pos: impl Into<Vec2<f32>>) -> f32 {
+        let pos = pos.into();
+        let (x, fx) = split(pos.x);
+        let (y, fy) = split(pos.y);
+        let (u, v) = (fade(fx), fade(fy));
+        let g = |dx, dy| {
+            value(self.perm.hash2(x.wrapping_add(dx), y.wrapping_add(dy)))
+        };
+
+        lerp(v, lerp(u, g(0, 0), g(1, 0)), lerp(u, g(0, 1), g(1, 1)))
+    }
+
+    fn sample3(&self, pos: impl Into<Vec3<f32>>) -> f32 {
+        let pos = pos.into();
+        let (x, fx) = split(pos.x);
+        let (y, fy) = split(pos.y);
+        let (z, fz) = split(pos.z);
+        let (u, v, w) = (fade(fx), fade(fy), fade(fz));
+        let g = |dx, dy, dz| {
+            value(self.perm.hash3(
+                x.wrapping_add(dx),
+                y.wrapping_add(dy),
+                z.wrapping_add(dz),
+            ))
+        };
+
+        let z0 = lerp(
+            v,
+            lerp(u, g(0, 0, 0), g(1, 0, 0)),
+            lerp(u, g(0, 1, 0), g(1, 1, 0)),
+        );
+        let z1 = lerp(
+            v,
+            lerp(u, g(0, 0, 1), g(1, 0, 1)),
+            lerp(u, g(0, 1, 1), g(1, 1, 1)),
+        );
+        lerp(w, z0, z1)
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{Noise, Vec2, Vec3};
+
+/// Domain warping. The position is offset by the warp noise before the
+/// noise is sampled.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Warp<N, W> {
+    noise: N,
+    warp: W,
+    strength: f32,
+}
+
+impl<N, W> Warp<N, W> {
+    /// Warp `noise` by `warp`. The strength of the warp is 1 by default.
+    pub fn new(noise: N, warp: W) -> Self {
+        Self {
+            noise,
+            warp,
+            strength: 1.,
+        }
+    }
+
+    /// Set the maximum distance by which the position is moved.
+    pub fn strength(mut self, strength: f32) -> Self {
+        self.strength = strength;
+        self
+    }
+}
+
+impl<N: Noise, W: Noise> Noise for Warp<N, W> {
+    fn sample2(&self, pos: impl Into<Vec2<f32>>) -> f32 {
+        let pos = pos.into();
+        // Sample the warp at distant positions so that the components are
+        // not correlated.
+        let offset = Vec2::new(
+            self.warp.sample2(pos),
+            self.warp.sample2(pos + Vec2::new(5.2, 1.3)),
+        );
+        self.noise.sample2(pos + offset * self.strength)
+    }
+
+    fn sample3(&self, pos: impl Into<Vec3<f32>>) -> f32 {
+        let pos = pos.into();
+        // Sample the warp at distant positions so that the components are
+        // not correlated.
+        let offset = Vec3::new(
+            self.warp.sample3(pos),
+            self.warp.sample3(pos + Vec3::new(5.2, 1.3, 2.8)),
+            self.warp.sample3(pos + Vec3::new(1.7, 9.2, 4.4)),
+        );
+        self.noise.sample3(pos + offset * self.strength)
+    }
+}
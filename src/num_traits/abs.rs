@@ -0,0 +1,28 @@
+/// Generic trait for getting the absolute value.
+pub trait Abs {
+    /// Get the absolute value. Unsigned values are returned unchanged.
+    fn abs(self) -> Self;
+}
+
+macro_rules! impl_abs_signed {
+    ($($i:ident),*) => {
+        $(impl Abs for $i {
+            fn abs(self) -> Self {
+                $i::abs(self)
+            }
+        })*
+    };
+}
+
+macro_rules! impl_abs_unsigned {
+    ($($i:ident),*) => {
+        $(impl Abs for $i {
+            fn abs(self) -> Self {
+                self
+            }
+        })*
+    };
+}
+
+impl_abs_signed!(i8, i16, i32, i64, isize, i128, f32, f64);
+impl_abs_unsigned!(u8, u16, u32, u64, usize, u128);
@@ -1,8 +1,6 @@
-/// Cast type to numeric type with smaller range. Ignore potential
-/// overflows.
+/// Cast type to other numeric type. Ignore potential overflows.
 pub trait Cast<O> {
-    /// Cast type to numeric type with smaller range. Ignore potential
-    /// overflows.
+    /// Cast type to other numeric type. Ignore potential overflows.
     fn cast(self) -> O;
 }
 
@@ -41,3 +39,18 @@ impl_cast!(f64, f32, u128, i128, isize, u64, i64, i32, i16, i8 -> usize);
 impl_cast!(f64, f32, u128, i128, usize, u64, i64, i32 -> isize);
 impl_cast!(f64, f32, i128, isize, i64, i32, i16, i8 -> u128);
 impl_cast!(f64, f32, u128 -> i128);
+
+// Lossless casts and casts to self.
+impl_cast!(f32 -> f32);
+impl_cast!(u8 -> u8);
+impl_cast!(i8 -> i8);
+impl_cast!(u8, u16 -> u16);
+impl_cast!(u8, i8, i16 -> i16);
+impl_cast!(u8, u16, u32 -> u32);
+impl_cast!(u8, i8, u16, i16, i32 -> i32);
+impl_cast!(u8, u16, u32, u64 -> u64);
+impl_cast!(u8, i8, u16, i16, u32, i32, i64 -> i64);
+impl_cast!(u8, u16, u32, usize -> usize);
+impl_cast!(u8, i8, u16, i16, u32, isize -> isize);
+impl_cast!(u8, u16, u32, u64, usize, u128 -> u128);
+impl_cast!(u8, i8, u16, i16, u32, i32, u64, i64, usize, isize, i128 -> i128);
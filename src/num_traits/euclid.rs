@@ -0,0 +1,50 @@
+use crate::FloatMath;
+
+/// Generic trait for types that support euclidean division.
+pub trait Euclid {
+    /// Calculate the quotient of euclidean division. The result is rounded
+    /// so that the remainder is never negative.
+    fn div_euclid(self, other: Self) -> Self;
+
+    /// Calculate the non negative remainder of euclidean division.
+    fn rem_euclid(self, other: Self) -> Self;
+}
+
+macro_rules! impl_euclid {
+    ($($i:ident),*) => {
+        $(impl Euclid for $i {
+            fn div_euclid(self, other: Self) -> Self {
+                $i::div_euclid(self, other)
+            }
+
+            fn rem_euclid(self, other: Self) -> Self {
+                $i::rem_euclid(self, other)
+            }
+        })*
+    };
+}
+
+macro_rules! impl_euclid_float {
+    ($($i:ident),*) => {
+        $(impl Euclid for $i {
+            fn div_euclid(self, other: Self) -> Self {
+                let q = FloatMath::trunc(self / other);
+                if self % other < 0. {
+                    if other > 0. { q - 1. } else { q + 1. }
+                } else {
+                    q
+                }
+            }
+
+            fn rem_euclid(self, other: Self) -> Self {
+                let r = self % other;
+                if r < 0. { r + other.abs() } else { r }
+            }
+        })*
+    };
+}
+
+impl_euclid!(
+    u8, i8, u16, i16, u32, i32, u64, i64, usize, isize, u128, i128
+);
+impl_euclid_float!(f32, f64);
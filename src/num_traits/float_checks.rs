@@ -0,0 +1,53 @@
+/// Generic trait for checking special float values. Integers are always
+/// finite.
+pub trait FloatChecks {
+    /// Check whether the value is neither infinite nor NaN.
+    fn is_finite(&self) -> bool;
+
+    /// Check whether the value is positive or negative infinity.
+    fn is_infinite(&self) -> bool;
+
+    /// Check whether the value is NaN.
+    fn is_nan(&self) -> bool;
+}
+
+macro_rules! impl_float_checks_int {
+    ($($i:ident),*) => {
+        $(impl FloatChecks for $i {
+            fn is_finite(&self) -> bool {
+                true
+            }
+
+            fn is_infinite(&self) -> bool {
+                false
+            }
+
+            fn is_nan(&self) -> bool {
+                false
+            }
+        })*
+    };
+}
+
+macro_rules! impl_float_checks_float {
+    ($($i:ident),*) => {
+        $(impl FloatChecks for $i {
+            fn is_finite(&self) -> bool {
+                $i::is_finite(*self)
+            }
+
+            fn is_infinite(&self) -> bool {
+                $i::is_infinite(*self)
+            }
+
+            fn is_nan(&self) -> bool {
+                $i::is_nan(*self)
+            }
+        })*
+    };
+}
+
+impl_float_checks_int!(
+    u8, i8, u16, i16, u32, i32, u64, i64, usize, isize, u128, i128
+);
+impl_float_checks_float!(f32, f64);
@@ -0,0 +1,170 @@
+/// Float functions that are provided by `std` or `libm`. Rounding is
+/// available even without them.
+pub(crate) trait FloatMath: Sized {
+    /// Round towards zero.
+    fn trunc(self) -> Self;
+
+    /// Round towards negative infinity.
+    fn floor(self) -> Self;
+
+    /// Round towards positive infinity.
+    fn ceil(self) -> Self;
+
+    /// Round to the nearest integer. Round half away from zero.
+    fn round(self) -> Self;
+
+    /// Calculate the square root.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn sqrt(self) -> Self;
+
+    /// Calculate the sinus.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn sin(self) -> Self;
+
+    /// Calculate the cosinus.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn cos(self) -> Self;
+
+    /// Calculate the atan(a/b) with correct sign.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn atan2(a: Self, b: Self) -> Self;
+
+    /// Calculate `e` to the power of the value.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn exp(self) -> Self;
+
+    /// Calculate the natural logarithm.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn ln(self) -> Self;
+}
+
+#[cfg(feature = "std")]
+macro_rules! impl_float_math {
+    ($($f:ident),*) => {
+        $(impl FloatMath for $f {
+            fn trunc(self) -> Self {
+                $f::trunc(self)
+            }
+
+            fn floor(self) -> Self {
+                $f::floor(self)
+            }
+
+            fn ceil(self) -> Self {
+                $f::ceil(self)
+            }
+
+            fn round(self) -> Self {
+                $f::round(self)
+            }
+
+            fn sqrt(self) -> Self {
+                $f::sqrt(self)
+            }
+
+            fn sin(self) -> Self {
+                $f::sin(self)
+            }
+
+            fn cos(self) -> Self {
+                $f::cos(self)
+            }
+
+            fn atan2(a: Self, b: Self) -> Self {
+                $f::atan2(a, b)
+            }
+
+            fn exp(self) -> Self {
+                $f::exp(self)
+            }
+
+            fn ln(self) -> Self {
+                $f::ln(self)
+            }
+        })*
+    };
+}
+
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+macro_rules! impl_float_math {
+    ($($f:ident),*) => {
+        $(impl FloatMath for $f {
+            fn trunc(self) -> Self {
+                libm::Libm::<$f>::trunc(self)
+            }
+
+            fn floor(self) -> Self {
+                libm::Libm::<$f>::floor(self)
+            }
+
+            fn ceil(self) -> Self {
+                libm::Libm::<$f>::ceil(self)
+            }
+
+            fn round(self) -> Self {
+                libm::Libm::<$f>::round(self)
+            }
+
+            fn sqrt(self) -> Self {
+                libm::Libm::<$f>::sqrt(self)
+            }
+
+            fn sin(self) -> Self {
+                libm::Libm::<$f>::sin(self)
+            }
+
+            fn cos(self) -> Self {
+                libm::Libm::<$f>::cos(self)
+            }
+
+            fn atan2(a: Self, b: Self) -> Self {
+                libm::Libm::<$f>::atan2(a, b)
+            }
+
+            fn exp(self) -> Self {
+                libm::Libm::<$f>::exp(self)
+            }
+
+            fn ln(self) -> Self {
+                libm::Libm::<$f>::log(self)
+            }
+        })*
+    };
+}
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+macro_rules! impl_float_math {
+    ($($f:ident),*) => {
+        $(impl FloatMath for $f {
+            fn trunc(self) -> Self {
+                // Floats this large have no fractional part.
+                if self.abs() < (1_u64 << ($f::MANTISSA_DIGITS - 1)) as $f {
+                    (self as i64 as $f).copysign(self)
+                } else {
+                    self
+                }
+            }
+
+            fn floor(self) -> Self {
+                let t = self.trunc();
+                if t > self { t - 1. } else { t }
+            }
+
+            fn ceil(self) -> Self {
+                let t = self.trunc();
+                if t < self { t + 1. } else { t }
+            }
+
+            fn round(self) -> Self {
+                let t = self.trunc();
+                if (self - t).abs() >= 0.5 {
+                    t + (1. as $f).copysign(self)
+                } else {
+                    t
+                }
+            }
+        })*
+    };
+}
+
+impl_float_math!(f32, f64);
@@ -1,4 +1,6 @@
 use crate::ContainingFloat;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::FloatMath;
 
 /// Trait that allows calculation of goniometric functions.
 pub trait Goniometric {
@@ -35,24 +37,26 @@ where
     }
 }
 
+#[cfg(any(feature = "std", feature = "libm"))]
 macro_rules! impl_float_goniometric {
     ($($t:ident),*) => {
         $(impl Goniometric for $t {
             type Output = $t;
 
             fn sin(self) -> Self::Output {
-                $t::sin(self)
+                FloatMath::sin(self)
             }
 
             fn cos(self) -> Self::Output {
-                $t::cos(self)
+                FloatMath::cos(self)
             }
 
             fn atan2(a: Self, b: Self) -> Self::Output {
-                $t::atan2(a, b)
+                FloatMath::atan2(a, b)
             }
         })*
     };
 }
 
+#[cfg(any(feature = "std", feature = "libm"))]
 impl_float_goniometric!(f32, f64);
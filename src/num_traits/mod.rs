@@ -1,7 +1,11 @@
+mod abs;
 mod cast;
 mod checked;
 mod containing_float;
+mod euclid;
 mod float;
+mod float_checks;
+mod float_math;
 mod goniometric;
 mod infinity;
 mod into_float;
@@ -9,14 +13,23 @@ mod isqrt;
 mod large_type;
 mod normal_limits;
 mod one;
+mod overflowing;
+mod round;
 mod saturating;
 mod scale;
+mod signum;
 mod sqrt;
+mod try_cast;
 mod two;
+mod wrapping;
 mod zero;
 
 pub use self::{
-    cast::*, checked::*, containing_float::*, float::*, goniometric::*,
-    infinity::*, into_float::*, isqrt::*, large_type::*, normal_limits::*,
-    one::*, saturating::*, scale::*, sqrt::*, two::*, zero::*,
+    abs::*, cast::*, checked::*, containing_float::*, euclid::*, float::*,
+    float_checks::*, goniometric::*, infinity::*, into_float::*, isqrt::*,
+    large_type::*, normal_limits::*, one::*, overflowing::*, round::*,
+    saturating::*, scale::*, signum::*, sqrt::*, try_cast::*, two::*,
+    wrapping::*, zero::*,
 };
+
+pub(crate) use self::float_math::FloatMath;
@@ -0,0 +1,44 @@
+/// Generic trait for types that support overflowing arithmetic.
+pub trait Overflowing: Sized {
+    /// Subtract the value. Returns the wrapped result and whether overflow
+    /// occurred.
+    fn overflowing_sub(self, other: Self) -> (Self, bool);
+
+    /// Add the value. Returns the wrapped result and whether overflow
+    /// occurred.
+    fn overflowing_add(self, other: Self) -> (Self, bool);
+
+    /// Multiply by the value. Returns the wrapped result and whether overflow
+    /// occurred.
+    fn overflowing_mul(self, other: Self) -> (Self, bool);
+
+    /// Negate the value. Returns the wrapped result and whether overflow
+    /// occurred.
+    fn overflowing_neg(self) -> (Self, bool);
+}
+
+macro_rules! impl_overflowing {
+    ($($i:ident),*) => {
+        $(impl Overflowing for $i {
+            fn overflowing_sub(self, other: Self) -> (Self, bool) {
+                $i::overflowing_sub(self, other)
+            }
+
+            fn overflowing_add(self, other: Self) -> (Self, bool) {
+                $i::overflowing_add(self, other)
+            }
+
+            fn overflowing_mul(self, other: Self) -> (Self, bool) {
+                $i::overflowing_mul(self, other)
+            }
+
+            fn overflowing_neg(self) -> (Self, bool) {
+                $i::overflowing_neg(self)
+            }
+        })*
+    };
+}
+
+impl_overflowing!(
+    u8, i8, u16, i16, u32, i32, u64, i64, usize, isize, u128, i128
+);
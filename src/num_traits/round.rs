@@ -0,0 +1,76 @@
+use crate::{FloatMath, Zero};
+
+/// Generic trait for rounding numbers. Integers are already rounded.
+pub trait Round {
+    /// Round towards negative infinity.
+    fn floor(self) -> Self;
+
+    /// Round towards positive infinity.
+    fn ceil(self) -> Self;
+
+    /// Round to the nearest integer. Round half away from zero.
+    fn round(self) -> Self;
+
+    /// Round towards zero.
+    fn trunc(self) -> Self;
+
+    /// Get the fractional part. This has the same sign as the value.
+    fn fract(self) -> Self;
+}
+
+macro_rules! impl_round_int {
+    ($($i:ident),*) => {
+        $(impl Round for $i {
+            fn floor(self) -> Self {
+                self
+            }
+
+            fn ceil(self) -> Self {
+                self
+            }
+
+            fn round(self) -> Self {
+                self
+            }
+
+            fn trunc(self) -> Self {
+                self
+            }
+
+            fn fract(self) -> Self {
+                Self::ZERO
+            }
+        })*
+    };
+}
+
+macro_rules! impl_round_float {
+    ($($i:ident),*) => {
+        $(impl Round for $i {
+            fn floor(self) -> Self {
+                FloatMath::floor(self)
+            }
+
+            fn ceil(self) -> Self {
+                FloatMath::ceil(self)
+            }
+
+            fn round(self) -> Self {
+                FloatMath::round(self)
+            }
+
+            fn trunc(self) -> Self {
+                FloatMath::trunc(self)
+            }
+
+            fn fract(self) -> Self {
+                self - FloatMath::trunc(self)
+            }
+        })*
+    };
+}
+
+impl_round_int!(
+    u8, i8, u16, i16, u32, i32, u64, i64, usize, isize, u128, i128
+);
+impl_round_float!(f32, f64);
@@ -0,0 +1,30 @@
+/// Generic trait for getting the sign of a number.
+pub trait Signum {
+    /// Get number representing the sign of the value. This is `-1` for
+    /// negative values, `1` for positive values. Integer zero returns `0`,
+    /// floats behave as [`f32::signum`].
+    fn signum(self) -> Self;
+}
+
+macro_rules! impl_signum_signed {
+    ($($i:ident),*) => {
+        $(impl Signum for $i {
+            fn signum(self) -> Self {
+                $i::signum(self)
+            }
+        })*
+    };
+}
+
+macro_rules! impl_signum_unsigned {
+    ($($i:ident),*) => {
+        $(impl Signum for $i {
+            fn signum(self) -> Self {
+                (self != 0) as $i
+            }
+        })*
+    };
+}
+
+impl_signum_signed!(i8, i16, i32, i64, isize, i128, f32, f64);
+impl_signum_unsigned!(u8, u16, u32, u64, usize, u128);
@@ -1,4 +1,6 @@
 use crate::ContainingFloat;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::FloatMath;
 
 /// Calculate the floating point square root.
 pub trait Sqrt {
@@ -20,16 +22,18 @@ where
     }
 }
 
+#[cfg(any(feature = "std", feature = "libm"))]
 macro_rules! impl_float_sqrt {
     ($($i:ident),*) => {
         $(impl Sqrt for $i {
             type Output = Self;
 
             fn sqrt(self) -> Self::Output {
-                $i::sqrt(self)
+                FloatMath::sqrt(self)
             }
         })*
     };
 }
 
+#[cfg(any(feature = "std", feature = "libm"))]
 impl_float_sqrt!(f32, f64);
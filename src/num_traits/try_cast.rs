@@ -0,0 +1,113 @@
+use crate::FloatMath;
+
+/// Cast type to other numeric type. Returns [`None`] if the value is not
+/// finite or if it is out of range of the other type. Floats are truncated
+/// when casted to integers.
+pub trait TryCast<O> {
+    /// Cast type to other numeric type. Returns [`None`] if the value is not
+    /// finite or if it is out of range of the other type.
+    fn try_cast(self) -> Option<O>;
+}
+
+macro_rules! impl_try_cast_int {
+    ($($i:ident),* -> $o:ident) => {
+        $(impl TryCast<$o> for $i {
+            fn try_cast(self) -> Option<$o> {
+                $o::try_from(self).ok()
+            }
+        })*
+    };
+}
+
+macro_rules! impl_try_cast_int_float {
+    ($($i:ident),* -> $o:ident) => {
+        $(impl TryCast<$o> for $i {
+            fn try_cast(self) -> Option<$o> {
+                let res = self as $o;
+                res.is_finite().then_some(res)
+            }
+        })*
+    };
+}
+
+macro_rules! impl_try_cast_float_int {
+    ($($i:ident),* -> $o:ident) => {
+        $(impl TryCast<$o> for $i {
+            fn try_cast(self) -> Option<$o> {
+                // Both bounds are powers of two, so they are exact.
+                let min = $o::MIN as $i;
+                let max = ($o::MAX / 2 + 1) as $i * 2.;
+                let t = FloatMath::trunc(self);
+                (t >= min && t < max).then_some(t as $o)
+            }
+        })*
+    };
+}
+
+macro_rules! impl_try_cast_float {
+    ($($i:ident),* -> $o:ident) => {
+        $(impl TryCast<$o> for $i {
+            fn try_cast(self) -> Option<$o> {
+                let res = self as $o;
+                (self.is_finite() && res.is_finite()).then_some(res)
+            }
+        })*
+    };
+}
+
+impl_try_cast_int!(
+    u8, i8, u16, i16, u32, i32, u64, i64, usize, isize, u128, i128 -> u8
+);
+impl_try_cast_int!(
+    u8, i8, u16, i16, u32, i32, u64, i64, usize, isize, u128, i128 -> i8
+);
+impl_try_cast_int!(
+    u8, i8, u16, i16, u32, i32, u64, i64, usize, isize, u128, i128 -> u16
+);
+impl_try_cast_int!(
+    u8, i8, u16, i16, u32, i32, u64, i64, usize, isize, u128, i128 -> i16
+);
+impl_try_cast_int!(
+    u8, i8, u16, i16, u32, i32, u64, i64, usize, isize, u128, i128 -> u32
+);
+impl_try_cast_int!(
+    u8, i8, u16, i16, u32, i32, u64, i64, usize, isize, u128, i128 -> i32
+);
+impl_try_cast_int!(
+    u8, i8, u16, i16, u32, i32, u64, i64, usize, isize, u128, i128 -> u64
+);
+impl_try_cast_int!(
+    u8, i8, u16, i16, u32, i32, u64, i64, usize, isize, u128, i128 -> i64
+);
+impl_try_cast_int!(
+    u8, i8, u16, i16, u32, i32, u64, i64, usize, isize, u128, i128 -> usize
+);
+impl_try_cast_int!(
+    u8, i8, u16, i16, u32, i32, u64, i64, usize, isize, u128, i128 -> isize
+);
+impl_try_cast_int!(
+    u8, i8, u16, i16, u32, i32, u64, i64, usize, isize, u128, i128 -> u128
+);
+impl_try_cast_int!(
+    u8, i8, u16, i16, u32, i32, u64, i64, usize, isize, u128, i128 -> i128
+);
+impl_try_cast_int_float!(
+    u8, i8, u16, i16, u32, i32, u64, i64, usize, isize, u128, i128 -> f32
+);
+impl_try_cast_int_float!(
+    u8, i8, u16, i16, u32, i32, u64, i64, usize, isize, u128, i128 -> f64
+);
+impl_try_cast_float_int!(f32, f64 -> u8);
+impl_try_cast_float_int!(f32, f64 -> i8);
+impl_try_cast_float_int!(f32, f64 -> u16);
+impl_try_cast_float_int!(f32, f64 -> i16);
+impl_try_cast_float_int!(f32, f64 -> u32);
+impl_try_cast_float_int!(f32, f64 -> i32);
+impl_try_cast_float_int!(f32, f64 -> u64);
+impl_try_cast_float_int!(f32, f64 -> i64);
+impl_try_cast_float_int!(f32, f64 -> usize);
+impl_try_cast_float_int!(f32, f64 -> isize);
+impl_try_cast_float_int!(f32, f64 -> u128);
+impl_try_cast_float_int!(f32, f64 -> i128);
+impl_try_cast_float!(f32, f64 -> f32);
+impl_try_cast_float!(f32, f64 -> f64);
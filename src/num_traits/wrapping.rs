@@ -0,0 +1,40 @@
+/// Generic trait for types that support wrapping arithmetic.
+pub trait Wrapping {
+    /// Subtract the value. Wrap around at the boundary of the type.
+    fn wrapping_sub(self, other: Self) -> Self;
+
+    /// Add the value. Wrap around at the boundary of the type.
+    fn wrapping_add(self, other: Self) -> Self;
+
+    /// Multiply by the value. Wrap around at the boundary of the type.
+    fn wrapping_mul(self, other: Self) -> Self;
+
+    /// Negate the value. Wrap around at the boundary of the type.
+    fn wrapping_neg(self) -> Self;
+}
+
+macro_rules! impl_wrapping {
+    ($($i:ident),*) => {
+        $(impl Wrapping for $i {
+            fn wrapping_sub(self, other: Self) -> Self {
+                $i::wrapping_sub(self, other)
+            }
+
+            fn wrapping_add(self, other: Self) -> Self {
+                $i::wrapping_add(self, other)
+            }
+
+            fn wrapping_mul(self, other: Self) -> Self {
+                $i::wrapping_mul(self, other)
+            }
+
+            fn wrapping_neg(self) -> Self {
+                $i::wrapping_neg(self)
+            }
+        })*
+    };
+}
+
+impl_wrapping!(
+    u8, i8, u16, i16, u32, i32, u64, i64, usize, isize, u128, i128
+);
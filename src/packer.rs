@@ -0,0 +1,478 @@
+use alloc::{vec, vec::Vec};
+use core::{
+    cmp::Ordering,
+    ops::{Add, Sub},
+};
+
+use crate::{Padding, Rect, RectExt, Vec2, Zero};
+
+/// Heuristic used by [`Packer`].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum PackAlgo {
+    /// Keep track of the top edge of the placed items and place new items as
+    /// low as possible. This is fast and works well for items of similar
+    /// height (e.g. glyphs).
+    #[default]
+    Skyline,
+    /// Keep track of all the maximal free rectangles and place new items to
+    /// the one that leaves the shortest side. This produces tighter packing,
+    /// but is slower.
+    MaxRects,
+}
+
+/// Item placed by [`Packer`].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Packed<T = usize> {
+    /// The area of the item in the bin (without padding).
+    pub rect: Rect<T>,
+    /// Whether the item was rotated by 90 degrees. If this is true, the
+    /// width and height of `rect` are swapped relative to the item size.
+    pub rotated: bool,
+}
+
+/// Horizontal segment of the skyline.
+#[derive(Debug, Copy, Clone)]
+struct Segment<T> {
+    x: T,
+    y: T,
+    width: T,
+}
+
+#[derive(Debug, Clone)]
+enum State<T> {
+    Skyline(Vec<Segment<T>>),
+    MaxRects(Vec<Rect<T>>),
+}
+
+/// Packs rectangles into single bin. Items may be inserted one by one as
+/// they come, or all at once with [`Packer::pack`] which gives better
+/// results.
+#[derive(Debug, Clone)]
+pub struct Packer<T = usize> {
+    size: Vec2<T>,
+    padding: Padding<T>,
+    rotate: bool,
+    state: State<T>,
+}
+
+impl<T> Packer<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + PartialOrd + Zero,
+{
+    /// Create empty bin with the given size that uses the given heuristic.
+    pub fn new(size: impl Into<Vec2<T>>, algo: PackAlgo) -> Self {
+        let mut res = Self {
+            size: size.into(),
+            padding: Padding::uniform(T::ZERO),
+            rotate: false,
+            state: State::Skyline(vec![]),
+        };
+        res.state = res.empty(algo);
+        res
+    }
+
+    /// Create empty bin that uses the skyline heuristic.
+    pub fn skyline(size: impl Into<Vec2<T>>) -> Self {
+        Self::new(size, PackAlgo::Skyline)
+    }
+
+    /// Create empty bin that uses the max rects heuristic.
+    pub fn max_rects(size: impl Into<Vec2<T>>) -> Self {
+        Self::new(size, PackAlgo::MaxRects)
+    }
+
+    /// Set the space reserved around each of the items.
+    pub fn padding(mut self, padding: impl Into<Padding<T>>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Set whether the items may be rotated by 90 degrees.
+    pub fn rotate(mut self, rotate: bool) -> Self {
+        self.rotate = rotate;
+        self
+    }
+
+    /// Get the size of the bin.
+    pub fn size(&self) -> Vec2<T> {
+        self.size
+    }
+
+    /// Get the heuristic used by the packer.
+    pub fn algo(&self) -> PackAlgo {
+        match self.state {
+            State::Skyline(_) => PackAlgo::Skyline,
+            State::MaxRects(_) => PackAlgo::MaxRects,
+        }
+    }
+
+    /// Remove all the items from the bin.
+    pub fn clear(&mut self) {
+        self.state = self.empty(self.algo());
+    }
+
+    /// Insert single item with the given size. Returns [`None`] if the item
+    /// doesn't fit.
+    pub fn insert(&mut self, size: impl Into<Vec2<T>>) -> Option<Packed<T>> {
+        let size = size.into();
+        let outer = size + self.padding.size();
+        if outer.x == T::ZERO || outer.y == T::ZERO {
+            return (outer.x <= self.size.x && outer.y <= self.size.y).then(
+                || Packed {
+                    rect: Rect::from_pos_size(self.padding.offset(), size),
+                    rotated: false,
+                },
+            );
+        }
+
+        let rotated = Vec2::new(size.y, size.x) + self.padding.size();
+        let pos = match &mut self.state {
+            State::Skyline(segs) => {
+                let find = |size, rot| {
+                    skyline_find(segs, self.size, size)
+                        .map(|(s, (i, p))| (s, (i, p, rot)))
+                };
+                let mut best = find(outer, false);
+                if self.rotate {
+                    best = better(best, find(rotated, true));
+                }
+                let (_, (i, pos, rot)) = best?;
+                let size = if rot { rotated } else { outer };
+                skyline_add(segs, i, pos, size);
+                (pos, rot)
+            }
+            State::MaxRects(free) => {
+                let find = |size, rot| {
+                    max_rects_find(free, size).map(|(s, p)| (s, (p, rot)))
+                };
+                let mut best = find(outer, false);
+                if self.rotate {
+                    best = better(best, find(rotated, true));
+                }
+                let (_, (pos, rot)) = best?;
+                let size = if rot { rotated } else { outer };
+                max_rects_add(free, Rect::from_pos_size(pos, size));
+                (pos, rot)
+            }
+        };
+
+        let (pos, rotated) = pos;
+        let size = if rotated {
+            Vec2::new(size.y, size.x)
+        } else {
+            size
+        };
+        Some(Packed {
+            rect: Rect::from_pos_size(pos + self.padding.offset(), size),
+            rotated,
+        })
+    }
+
+    /// Insert multiple items at once. The items are inserted from the
+    /// largest, so the results are usually better than when inserting them
+    /// one by one. Returns the results in the same order as the items.
+    pub fn pack(
+        &mut self,
+        sizes: impl IntoIterator<Item = impl Into<Vec2<T>>>,
+    ) -> Vec<Option<Packed<T>>> {
+        let sizes: Vec<Vec2<T>> =
+            sizes.into_iter().map(|s| s.into()).collect();
+        let mut order: Vec<_> = (0..sizes.len()).collect();
+        let key = |s: Vec2<T>| {
+            if s.x < s.y { (s.y, s.x) } else { (s.x, s.y) }
+        };
+        order.sort_by(|a, b| {
+            key(sizes[*b])
+                .partial_cmp(&key(sizes[*a]))
+                .unwrap_or(Ordering::Equal)
+        });
+
+        let mut res = vec![None; sizes.len()];
+        for i in order {
+            res[i] = self.insert(sizes[i]);
+        }
+        res
+    }
+
+    fn empty(&self, algo: PackAlgo) -> State<T> {
+        match algo {
+            PackAlgo::Skyline => State::Skyline(vec![Segment {
+                x: T::ZERO,
+                y: T::ZERO,
+                width: self.size.x,
+            }]),
+            PackAlgo::MaxRects => State::MaxRects(vec![Rect::from_pos_size(
+                Vec2::new(T::ZERO, T::ZERO),
+                self.size,
+            )]),
+        }
+    }
+}
+
+/// Found position with its score.
+type Found<T, P> = Option<((T, T), P)>;
+
+/// Choose the result with the lower score. Prefer `a` if they are equal.
+fn better<S: PartialOrd, P>(
+    a: Option<(S, P)>,
+    b: Option<(S, P)>,
+) -> Option<(S, P)> {
+    match (a, b) {
+        (Some(a), Some(b)) if b.0 < a.0 => Some(b),
+        (None, b) => b,
+        (a, _) => a,
+    }
+}
+
+/// Find the lowest position for item with the given size. Returns the score,
+/// the index of the first segment under the item and the position.
+fn skyline_find<T>(
+    segs: &[Segment<T>],
+    bin: Vec2<T>,
+    size: Vec2<T>,
+) -> Found<T, (usize, Vec2<T>)>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + PartialOrd + Zero,
+{
+    let mut best = None;
+    for (i, s) in segs.iter().enumerate() {
+        if size.x > bin.x - s.x {
+            break;
+        }
+
+        let mut y = T::ZERO;
+        let mut left = size.x;
+        for s in &segs[i..] {
+            if s.y > y {
+                y = s.y;
+            }
+            if s.width >= left {
+                break;
+            }
+            left = left - s.width;
+        }
+
+        if size.y > bin.y - y {
+            continue;
+        }
+
+        let res = Some(((y + size.y, s.x), (i, Vec2::new(s.x, y))));
+        best = better(best, res);
+    }
+    best
+}
+
+/// Raise the skyline with item at the given position that starts at the
+/// segment `i`.
+fn skyline_add<T>(
+    segs: &mut Vec<Segment<T>>,
+    i: usize,
+    pos: Vec2<T>,
+    size: Vec2<T>,
+) where
+    T: Copy + Add<Output = T> + Sub<Output = T> + PartialOrd,
+{
+    let end = pos.x + size.x;
+    segs.insert(
+        i,
+        Segment {
+            x: pos.x,
+            y: pos.y + size.y,
+            width: size.x,
+        },
+    );
+
+    let j = i + 1;
+    while j < segs.len() && segs[j].x < end {
+        let s_end = segs[j].x + segs[j].width;
+        if s_end <= end {
+            segs.remove(j);
+        } else {
+            segs[j].width = s_end - end;
+            segs[j].x = end;
+            break;
+        }
+    }
+
+    let mut k = segs.len() - 1;
+    while k > 0 {
+        if segs[k - 1].y == segs[k].y {
+            segs[k - 1].width = segs[k - 1].width + segs[k].width;
+            segs.remove(k);
+        }
+        k -= 1;
+    }
+}
+
+/// Find the free rectangle that leaves the shortest side when the item with
+/// the given size is placed in it. Returns the score and the position.
+fn max_rects_find<T>(free: &[Rect<T>], size: Vec2<T>) -> Found<T, Vec2<T>>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + PartialOrd,
+{
+    let mut best = None;
+    for f in free {
+        if size.x > f.width() || size.y > f.height() {
+            continue;
+        }
+
+        let a = f.width() - size.x;
+        let b = f.height() - size.y;
+        let score = if a < b { (a, b) } else { (b, a) };
+        best = better(best, Some((score, f.pos())));
+    }
+    best
+}
+
+/// Split the free rectangles that overlap the placed rectangle and remove
+/// the free rectangles that are not maximal.
+fn max_rects_add<T>(free: &mut Vec<Rect<T>>, p: Rect<T>)
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + PartialOrd,
+{
+    let mut i = 0;
+    while i < free.len() {
+        let f = free[i];
+        if p.left() >= f.right()
+            || p.right() <= f.left()
+            || p.top() >= f.bottom()
+            || p.bottom() <= f.top()
+        {
+            i += 1;
+            continue;
+        }
+
+        free.swap_remove(i);
+        if p.left() > f.left() {
+            free.push(Rect::from_ranges(f.left()..p.left(), f.yrange()));
+        }
+        if p.right() < f.right() {
+            free.push(Rect::from_ranges(p.right()..f.right(), f.yrange()));
+        }
+        if p.top() > f.top() {
+            free.push(Rect::from_ranges(f.xrange(), f.top()..p.top()));
+        }
+        if p.bottom() < f.bottom() {
+            free.push(Rect::from_ranges(f.xrange(), p.bottom()..f.bottom()));
+        }
+    }
+
+    let mut i = 0;
+    while i < free.len() {
+        let contained = free.iter().enumerate().any(|(j, f)| {
+            j != i && f.encloses(&free[i]) && (j < i || *f != free[i])
+        });
+        if contained {
+            free.swap_remove(i);
+        } else {
+            i += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Check that the items with padding are inside the bin and don't
+    /// overlap.
+    fn check(packer: &Packer, items: &[Packed]) {
+        let outer: Vec<_> =
+            items.iter().map(|p| p.rect + packer.padding).collect();
+        let bin = Rect::from_pos_size((0, 0), packer.size());
+        for (i, a) in outer.iter().enumerate() {
+            assert!(bin.encloses(a), "{a:?} is outside of the bin");
+            for b in &outer[i + 1..] {
+                assert!(
+                    a.right() <= b.left()
+                        || b.right() <= a.left()
+                        || a.bottom() <= b.top()
+                        || b.bottom() <= a.top(),
+                    "{a:?} overlaps {b:?}"
+                );
+            }
+        }
+    }
+
+    fn fill(mut packer: Packer, size: (usize, usize), cnt: usize) {
+        let items: Vec<_> =
+            (0..cnt).map(|_| packer.insert(size).unwrap()).collect();
+        check(&packer, &items);
+        assert_eq!(packer.insert(size), None);
+    }
+
+    #[test]
+    fn skyline() {
+        let mut p = Packer::skyline((10, 10));
+        let a = p.insert((6, 4)).unwrap();
+        let b = p.insert((4, 2)).unwrap();
+        let c = p.insert((4, 3)).unwrap();
+        assert_eq!(a.rect, Rect::from_pos_size((0, 0), (6, 4)));
+        assert_eq!(b.rect, Rect::from_pos_size((6, 0), (4, 2)));
+        assert_eq!(c.rect, Rect::from_pos_size((6, 2), (4, 3)));
+        check(&p, &[a, b, c]);
+        assert_eq!(p.insert((11, 1)), None);
+
+        fill(Packer::skyline((10, 10)), (5, 2), 10);
+    }
+
+    #[test]
+    fn max_rects() {
+        let mut p = Packer::max_rects((10, 10));
+        let a = p.insert((6, 4)).unwrap();
+        let b = p.insert((4, 10)).unwrap();
+        let c = p.insert((6, 6)).unwrap();
+        assert_eq!(a.rect, Rect::from_pos_size((0, 0), (6, 4)));
+        assert_eq!(b.rect, Rect::from_pos_size((6, 0), (4, 10)));
+        assert_eq!(c.rect, Rect::from_pos_size((0, 4), (6, 6)));
+        check(&p, &[a, b, c]);
+        assert_eq!(p.insert((1, 1)), None);
+
+        fill(Packer::max_rects((10, 10)), (5, 2), 10);
+    }
+
+    #[test]
+    fn rotate() {
+        for algo in [PackAlgo::Skyline, PackAlgo::MaxRects] {
+            let mut p = Packer::new((10, 4), algo);
+            assert_eq!(p.insert((2, 8)), None);
+
+            let mut p = Packer::new((10, 4), algo).rotate(true);
+            let a = p.insert((2, 8)).unwrap();
+            assert!(a.rotated);
+            assert_eq!(a.rect, Rect::from_pos_size((0, 0), (8, 2)));
+            let b = p.insert((3, 2)).unwrap();
+            check(&p, &[a, b]);
+        }
+    }
+
+    #[test]
+    fn padding() {
+        for algo in [PackAlgo::Skyline, PackAlgo::MaxRects] {
+            let mut p = Packer::new((10, 10), algo).padding(1);
+            let a = p.insert((3, 3)).unwrap();
+            assert_eq!(a.rect, Rect::from_pos_size((1, 1), (3, 3)));
+            let b = p.insert((3, 3)).unwrap();
+            assert_eq!(b.rect, Rect::from_pos_size((6, 1), (3, 3)));
+            check(&p, &[a, b]);
+            assert_eq!(p.insert((9, 1)), None);
+
+            fill(Packer::new((10, 10), algo).padding(1), (3, 3), 4);
+        }
+    }
+
+    #[test]
+    fn rotate_asymmetric_padding() {
+        for algo in [PackAlgo::Skyline, PackAlgo::MaxRects] {
+            let pad = Padding::new(3, 0, 0, 0);
+            let mut p = Packer::new((20, 5), algo).padding(pad).rotate(true);
+            let a = p.insert((2, 10)).unwrap();
+            let b = p.insert((2, 10)).unwrap();
+            assert!(a.rotated && b.rotated);
+            assert_eq!(a.rect, Rect::from_pos_size((3, 0), (10, 2)));
+            assert_eq!(b.rect, Rect::from_pos_size((3, 2), (10, 2)));
+            check(&p, &[a, b]);
+            assert_eq!(p.insert((2, 10)), None);
+        }
+    }
+}
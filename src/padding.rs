@@ -1,9 +1,10 @@
-use std::ops::{Add, AddAssign, Deref, DerefMut, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Deref, DerefMut, Sub, SubAssign};
 
 use crate::{MapExt, Rect, Vec2, Vec4, Zero};
 
 /// Type that represents padding.
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default, Hash)]
+#[repr(transparent)]
 pub struct Padding<T = usize>(pub Vec4<T>);
 
 impl<T: Copy> Padding<T> {
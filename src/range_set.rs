@@ -0,0 +1,315 @@
+use alloc::{vec, vec::Vec};
+use core::{ops::Range, slice};
+
+use crate::RangeExt;
+
+/// Set of values represented by sorted non overlapping ranges. Ranges that
+/// touch are merged and empty ranges are never stored.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> RangeSet<T> {
+    /// Create empty range set.
+    pub const fn new() -> Self {
+        Self { ranges: vec![] }
+    }
+
+    /// Get the ranges in the set as sorted slice.
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    /// Iterate over the ranges in the set in order.
+    pub fn iter(&self) -> slice::Iter<'_, Range<T>> {
+        self.ranges.iter()
+    }
+
+    /// Get the number of disjoint ranges in the set.
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// Check whether the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Remove all the ranges from the set.
+    pub fn clear(&mut self) {
+        self.ranges.clear();
+    }
+}
+
+impl<T: Copy + PartialOrd> RangeSet<T> {
+    /// Get the range that spans from the start of the first range to the end
+    /// of the last range.
+    pub fn bounds(&self) -> Option<Range<T>> {
+        let first = self.ranges.first()?;
+        let last = self.ranges.last()?;
+        Some(first.start..last.end)
+    }
+
+    /// Add the range to the set. Ranges that overlap or touch the new range
+    /// are merged with it.
+    pub fn insert(&mut self, range: impl Into<Range<T>>) {
+        let mut range = range.into();
+        if range.start >= range.end {
+            return;
+        }
+
+        let i = self.ranges.partition_point(|r| r.end < range.start);
+        let j = self.ranges.partition_point(|r| r.start <= range.end);
+        if i < j {
+            range = range.join_gap(self.ranges[i].clone());
+            range = range.join_gap(self.ranges[j - 1].clone());
+        }
+        self.ranges.splice(i..j, [range]);
+    }
+
+    /// Remove the range from the set. Ranges that overlap with it are
+    /// shrinked or split.
+    pub fn remove(&mut self, range: impl Into<Range<T>>) {
+        let range = range.into();
+        if range.start >= range.end {
+            return;
+        }
+
+        let i = self.ranges.partition_point(|r| r.end <= range.start);
+        let j = self.ranges.partition_point(|r| r.start < range.end);
+        if i >= j {
+            return;
+        }
+
+        let mut parts = vec![];
+        for r in &self.ranges[i..j] {
+            let (a, b) = r.clone().sub_range(range.clone());
+            parts.extend([a].into_iter().chain(b).filter(|r| r.start < r.end));
+        }
+        self.ranges.splice(i..j, parts);
+    }
+
+    /// Check whether the set contains the given value.
+    pub fn contains(&self, v: &T) -> bool {
+        let i = self.ranges.partition_point(|r| r.end <= *v);
+        self.ranges.get(i).is_some_and(|r| RangeExt::contains(r, v))
+    }
+
+    /// Check whether the whole range is contained in the set.
+    pub fn encloses(&self, range: impl Into<Range<T>>) -> bool {
+        let range = range.into();
+        if range.start >= range.end {
+            return true;
+        }
+        let i = self.ranges.partition_point(|r| r.end <= range.start);
+        self.ranges.get(i).is_some_and(|r| r.encloses(range))
+    }
+
+    /// Check whether any part of the range is in the set.
+    pub fn intersects(&self, range: impl Into<Range<T>>) -> bool {
+        let range = range.into();
+        if range.start >= range.end {
+            return false;
+        }
+        let i = self.ranges.partition_point(|r| r.end <= range.start);
+        self.ranges.get(i).is_some_and(|r| r.intersects(range))
+    }
+
+    /// Create set with values that are in any of the sets.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut res = self.clone();
+        res.extend(other.iter().cloned());
+        res
+    }
+
+    /// Create set with values that are in both of the sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let mut a = self.ranges.iter().peekable();
+        let mut b = other.ranges.iter().peekable();
+        while let (Some(ra), Some(rb)) = (a.peek(), b.peek()) {
+            if ra.intersects((*rb).clone()) {
+                ranges.push((*ra).clone().intersect((*rb).clone()));
+            }
+            if ra.end < rb.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        Self { ranges }
+    }
+
+    /// Create set with values that are in this set but not in the other set.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut res = self.clone();
+        for r in other {
+            res.remove(r.clone());
+        }
+        res
+    }
+
+    /// Get the ranges within `bounds` that are not in the set.
+    pub fn gaps(&self, bounds: impl Into<Range<T>>) -> Self {
+        let bounds = bounds.into();
+        let mut ranges = vec![];
+        let mut pos = bounds.start;
+        let i = self.ranges.partition_point(|r| r.end <= bounds.start);
+        for r in &self.ranges[i..] {
+            if r.start >= bounds.end {
+                break;
+            }
+            if r.start > pos {
+                ranges.push(pos..r.start);
+            }
+            pos = r.end;
+        }
+        if pos < bounds.end {
+            ranges.push(pos..bounds.end);
+        }
+        Self { ranges }
+    }
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + PartialOrd, R: Into<Range<T>>> Extend<R> for RangeSet<T> {
+    fn extend<I: IntoIterator<Item = R>>(&mut self, iter: I) {
+        for r in iter {
+            self.insert(r);
+        }
+    }
+}
+
+impl<T: Copy + PartialOrd, R: Into<Range<T>>> FromIterator<R> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = R>>(iter: I) -> Self {
+        let mut res = Self::new();
+        res.extend(iter);
+        res
+    }
+}
+
+impl<T> IntoIterator for RangeSet<T> {
+    type Item = Range<T>;
+    type IntoIter = vec::IntoIter<Range<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ranges.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a RangeSet<T> {
+    type Item = &'a Range<T>;
+    type IntoIter = slice::Iter<'a, Range<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ranges.iter()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::single_range_in_vec_init)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[Range<u32>]) -> RangeSet<u32> {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn insert_merges() {
+        let mut s = set(&[0..2, 4..6]);
+        assert_eq!(s.ranges(), [0..2, 4..6]);
+        s.insert(2..4);
+        assert_eq!(s.ranges(), [0..6]);
+        s.insert(6..8);
+        assert_eq!(s.ranges(), [0..8]);
+        s.insert(10..12);
+        s.insert(3..3);
+        assert_eq!(s.ranges(), [0..8, 10..12]);
+        s.insert(7..11);
+        assert_eq!(s.ranges(), [0..12]);
+        assert_eq!(set(&[5..7, 1..2, 0..1]).ranges(), [0..2, 5..7]);
+    }
+
+    #[test]
+    fn remove_splits() {
+        let mut s = set(&[0..10]);
+        s.remove(3..5);
+        assert_eq!(s.ranges(), [0..3, 5..10]);
+        s.remove(0..1);
+        s.remove(9..20);
+        assert_eq!(s.ranges(), [1..3, 5..9]);
+        s.remove(4..5);
+        s.remove(6..6);
+        assert_eq!(s.ranges(), [1..3, 5..9]);
+        s.remove(2..6);
+        assert_eq!(s.ranges(), [1..2, 6..9]);
+        s.remove(0..10);
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[0..5, 10..15, 20..25]);
+        let b = set(&[3..12, 14..21, 30..31]);
+        assert_eq!(a.union(&b).ranges(), [0..25, 30..31]);
+        assert_eq!(
+            a.intersection(&b).ranges(),
+            [3..5, 10..12, 14..15, 20..21]
+        );
+        assert_eq!(a.difference(&b).ranges(), [0..3, 12..14, 21..25]);
+        assert_eq!(b.difference(&a).ranges(), [5..10, 15..20, 30..31]);
+        assert_eq!(a.gaps(2..22).ranges(), [5..10, 15..20]);
+        assert_eq!(a.gaps(30..40).ranges(), [30..40]);
+        assert!(a.gaps(11..14).is_empty());
+        assert_eq!(a.bounds(), Some(0..25));
+    }
+
+    #[test]
+    fn queries() {
+        let s = set(&[0..5, 10..15]);
+        assert!(s.contains(&0) && s.contains(&4) && s.contains(&10));
+        assert!(!s.contains(&5) && !s.contains(&15));
+        assert!(s.encloses(1..5) && !s.encloses(4..11));
+        assert!(s.intersects(4..11) && !s.intersects(5..10));
+    }
+
+    #[test]
+    fn matches_reference() {
+        let mut s = RangeSet::new();
+        let mut reference = [false; 64];
+        let mut seed = 7_u64;
+        let mut next = |max: u64| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((seed >> 33) % max) as u32
+        };
+
+        for _ in 0..500 {
+            let start = next(56);
+            let r = start..start + next(8);
+            let add = next(2) == 0;
+            for v in r.clone() {
+                reference[v as usize] = add;
+            }
+            if add {
+                s.insert(r);
+            } else {
+                s.remove(r);
+            }
+
+            for (v, &inside) in (0..).zip(&reference) {
+                assert_eq!(s.contains(&v), inside, "value {v}");
+            }
+            assert!(s.ranges().windows(2).all(|w| w[0].end < w[1].start));
+            assert!(s.iter().all(|r| r.start < r.end));
+        }
+    }
+}
@@ -1,9 +1,12 @@
-use std::ops::{Add, AddAssign, Deref, DerefMut, Range, Sub, SubAssign};
+use core::ops::{
+    Add, AddAssign, Deref, DerefMut, Div, Mul, Range, Sub, SubAssign,
+};
 
-use crate::{One, Padding, RectExt, Vec2, Vec2RangeIter, Vec4, Zero};
+use crate::{Cast, One, Padding, RectExt, Vec2, Vec2RangeIter, Vec4, Zero};
 
 /// Rectangle.
-#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
 pub struct Rect<T = usize>(pub Vec4<T>);
 
 impl<T> Rect<T> {
@@ -102,16 +105,22 @@ where
 
 impl<T> IntoIterator for Rect<T>
 where
-    T: Copy + AddAssign + PartialOrd + One,
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Div<Output = T>
+        + Mul<Output = T>
+        + PartialOrd
+        + One
+        + Cast<usize>,
+    usize: Cast<T>,
 {
     type Item = Vec2<T>;
 
     type IntoIter = Vec2RangeIter<T>;
 
     fn into_iter(self) -> Self::IntoIter {
-        let mut bl = self.xy();
-        bl += self.zw();
-        self.xy().to(bl)
+        self.positions()
     }
 }
 
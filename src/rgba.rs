@@ -1,8 +1,9 @@
-use std::ops::{Deref, DerefMut, Mul};
+use core::ops::{Deref, DerefMut, Mul};
 
-use crate::{Float, MapExt, NormalLimits, Scale, Vec4};
+use crate::{Float, MapExt, NormalLimits, Scale, Vec4, f32x4::F32x4};
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
 pub struct Rgba<T = u8>(pub Vec4<T>);
 
 impl<T> Rgba<T> {
@@ -171,6 +172,24 @@ impl<T: NormalLimits> Rgba<T> {
         Self::rgba(T::NORM_MIN, T::NORM_MIN, T::NORM_MIN, T::NORM_MIN);
 }
 
+impl Rgba<f32> {
+    /// Blend this color over the other color. Both of the colors have
+    /// straight (not premultiplied) alpha.
+    pub fn over(self, dst: Self) -> Self {
+        let sa = self.a();
+        let da = dst.a() * (1. - sa);
+        let a = sa + da;
+        if a <= 0. {
+            return Self::TRANSPARENT;
+        }
+
+        let rgb = F32x4::from(self) * sa + F32x4::from(dst) * da;
+        let mut res: Self = (rgb / a).into();
+        res.w = a;
+        res
+    }
+}
+
 impl<T> Deref for Rgba<T> {
     type Target = Vec4<T>;
 
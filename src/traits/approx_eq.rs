@@ -0,0 +1,247 @@
+use crate::{Complex, Padding, Rect, Rgba, Vec2, Vec3, Vec4};
+
+/// Approximate equality of floats and types composed of floats. Types with
+/// multiple components are equal if all the components are equal.
+pub trait ApproxEq {
+    /// Type of the tolerance.
+    type Epsilon: Copy;
+
+    /// Type of the componentwise absolute difference.
+    type Diff;
+
+    /// Default tolerance used by [`ApproxEq::approx_eq`]. For floats this is
+    /// the machine epsilon.
+    const DEFAULT_EPSILON: Self::Epsilon;
+
+    /// Get the componentwise absolute difference.
+    fn abs_diff(&self, other: &Self) -> Self::Diff;
+
+    /// Check whether the absolute difference is at most `eps`.
+    fn abs_eq(&self, other: &Self, eps: Self::Epsilon) -> bool;
+
+    /// Check whether the absolute difference is at most `eps` or whether the
+    /// difference relative to the larger of the values is at most
+    /// `max_rel`.
+    fn rel_eq(
+        &self,
+        other: &Self,
+        eps: Self::Epsilon,
+        max_rel: Self::Epsilon,
+    ) -> bool;
+
+    /// Check whether the absolute difference is at most `eps` or whether
+    /// there are at most `max_ulps` representable values between the values.
+    fn ulps_eq(&self, other: &Self, eps: Self::Epsilon, max_ulps: u32)
+    -> bool;
+
+    /// Check whether the values are equal with the default tolerance. This
+    /// uses [`ApproxEq::rel_eq`] with [`ApproxEq::DEFAULT_EPSILON`] as both
+    /// the absolute and the relative tolerance, so for floats the values may
+    /// differ only by about one unit in the last place. Results of
+    /// calculations usually need larger tolerance.
+    fn approx_eq(&self, other: &Self) -> bool {
+        self.rel_eq(other, Self::DEFAULT_EPSILON, Self::DEFAULT_EPSILON)
+    }
+}
+
+macro_rules! impl_approx_eq_float {
+    ($($f:ident -> $i:ident),*) => {
+        $(impl ApproxEq for $f {
+            type Epsilon = $f;
+
+            type Diff = $f;
+
+            const DEFAULT_EPSILON: Self::Epsilon = $f::EPSILON;
+
+            fn abs_diff(&self, other: &Self) -> Self::Diff {
+                (self - other).abs()
+            }
+
+            fn abs_eq(&self, other: &Self, eps: Self::Epsilon) -> bool {
+                // Equality check is needed for infinities.
+                self == other || (self - other).abs() <= eps
+            }
+
+            fn rel_eq(
+                &self,
+                other: &Self,
+                eps: Self::Epsilon,
+                max_rel: Self::Epsilon,
+            ) -> bool {
+                if self.abs_eq(other, eps) {
+                    return true;
+                }
+                if self.is_infinite() || other.is_infinite() {
+                    return false;
+                }
+                let largest = self.abs().max(other.abs());
+                (self - other).abs() <= largest * max_rel
+            }
+
+            fn ulps_eq(
+                &self,
+                other: &Self,
+                eps: Self::Epsilon,
+                max_ulps: u32,
+            ) -> bool {
+                if self.abs_eq(other, eps) {
+                    return true;
+                }
+                if self.is_nan()
+                    || other.is_nan()
+                    || self.is_sign_negative() != other.is_sign_negative()
+                {
+                    return false;
+                }
+                // Values with the same sign are ordered as their bits.
+                let diff = (self.to_bits() as $i)
+                    .wrapping_sub(other.to_bits() as $i)
+                    .unsigned_abs();
+                diff <= max_ulps.into()
+            }
+        })*
+    };
+}
+
+impl_approx_eq_float!(f32 -> i32, f64 -> i64);
+
+macro_rules! impl_approx_eq_vec {
+    ($($t:ident { $($f:tt),* }),*) => {
+        $(impl<T: ApproxEq> ApproxEq for $t<T> {
+            type Epsilon = T::Epsilon;
+
+            type Diff = $t<T::Diff>;
+
+            const DEFAULT_EPSILON: Self::Epsilon = T::DEFAULT_EPSILON;
+
+            fn abs_diff(&self, other: &Self) -> Self::Diff {
+                $t { $($f: self.$f.abs_diff(&other.$f)),* }
+            }
+
+            fn abs_eq(&self, other: &Self, eps: Self::Epsilon) -> bool {
+                $(self.$f.abs_eq(&other.$f, eps))&&*
+            }
+
+            fn rel_eq(
+                &self,
+                other: &Self,
+                eps: Self::Epsilon,
+                max_rel: Self::Epsilon,
+            ) -> bool {
+                $(self.$f.rel_eq(&other.$f, eps, max_rel))&&*
+            }
+
+            fn ulps_eq(
+                &self,
+                other: &Self,
+                eps: Self::Epsilon,
+                max_ulps: u32,
+            ) -> bool {
+                $(self.$f.ulps_eq(&other.$f, eps, max_ulps))&&*
+            }
+        })*
+    };
+}
+
+impl_approx_eq_vec!(
+    Vec2 { x, y },
+    Vec3 { x, y, z },
+    Vec4 { x, y, z, w },
+    Rect { 0 },
+    Padding { 0 },
+    Rgba { 0 },
+    Complex { 0 }
+);
+
+/// Assert that two values are approximately equal using [`ApproxEq`].
+///
+/// By default [`ApproxEq::approx_eq`] is used which allows only very small
+/// relative difference. The comparison may be chosen with one of
+/// `abs = eps`, `rel = max_rel` or `ulps = max_ulps`. Relative and ULPs
+/// comparisons use [`ApproxEq::DEFAULT_EPSILON`] as the absolute tolerance.
+/// For example `assert_approx_eq!(a, b, abs = 0.01)`. On failure the values,
+/// their componentwise absolute difference and the tolerance are printed.
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_approx_eq!(
+            @check(true) l, r, d: $left, $right, approx_eq(),
+            "eps = {:?}, rel = {:?}", d, d
+        )
+    };
+    ($left:expr, $right:expr, abs = $eps:expr $(,)?) => {
+        $crate::assert_approx_eq!(
+            @check(true) l, r, d: $left, $right, abs_eq($eps),
+            "eps = {:?}", $eps
+        )
+    };
+    ($left:expr, $right:expr, rel = $rel:expr $(,)?) => {
+        $crate::assert_approx_eq!(
+            @check(true) l, r, d: $left, $right, rel_eq(d, $rel),
+            "eps = {:?}, rel = {:?}", d, $rel
+        )
+    };
+    ($left:expr, $right:expr, ulps = $ulps:expr $(,)?) => {
+        $crate::assert_approx_eq!(
+            @check(true) l, r, d: $left, $right, ulps_eq(d, $ulps),
+            "eps = {:?}, ulps = {:?}", d, $ulps
+        )
+    };
+    (
+        @check($expect:literal) $l:ident, $r:ident, $d:ident:
+        $left:expr, $right:expr, $fun:ident($($arg:expr),*), $($mode:tt)*
+    ) => {
+        match (&$left, &$right) {
+            ($l, $r) => {
+                fn default_epsilon<T: $crate::ApproxEq>(_: &T) -> T::Epsilon {
+                    T::DEFAULT_EPSILON
+                }
+                #[allow(unused_variables)]
+                let $d = default_epsilon($l);
+                if $crate::ApproxEq::$fun($l, $r, $($arg),*) != $expect {
+                    ::core::panic!(
+                        ::core::concat!(
+                            "assertion `left {} right` failed ({})\n",
+                            "  left: {:?}\n right: {:?}\n  diff: {:?}",
+                        ),
+                        if $expect { "≈" } else { "≉" },
+                        ::core::format_args!($($mode)*),
+                        $l,
+                        $r,
+                        $crate::ApproxEq::abs_diff($l, $r),
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Assert that two values are not approximately equal using [`ApproxEq`].
+/// The comparison is chosen in the same way as with [`assert_approx_eq`].
+#[macro_export]
+macro_rules! assert_approx_ne {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_approx_eq!(
+            @check(false) l, r, d: $left, $right, approx_eq(),
+            "eps = {:?}, rel = {:?}", d, d
+        )
+    };
+    ($left:expr, $right:expr, abs = $eps:expr $(,)?) => {
+        $crate::assert_approx_eq!(
+            @check(false) l, r, d: $left, $right, abs_eq($eps),
+            "eps = {:?}", $eps
+        )
+    };
+    ($left:expr, $right:expr, rel = $rel:expr $(,)?) => {
+        $crate::assert_approx_eq!(
+            @check(false) l, r, d: $left, $right, rel_eq(d, $rel),
+            "eps = {:?}, rel = {:?}", d, $rel
+        )
+    };
+    ($left:expr, $right:expr, ulps = $ulps:expr $(,)?) => {
+        $crate::assert_approx_eq!(
+            @check(false) l, r, d: $left, $right, ulps_eq(d, $ulps),
+            "eps = {:?}, ulps = {:?}", d, $ulps
+        )
+    };
+}
@@ -1,6 +1,9 @@
-use std::ops::{Div, DivAssign, Mul, MulAssign, Rem, RemAssign};
+use core::ops::{Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub};
 
-use crate::{MapExt, Saturating, Vec2, Vec3, Vec4};
+use crate::{
+    Euclid, MapExt, Overflowing, Rect, Rgba, Saturating, Two, Vec2, Vec3,
+    Vec4, Wrapping,
+};
 
 /// Trait for componentwise arithmetic.
 pub trait CompArithm: MapExt {
@@ -137,6 +140,256 @@ pub trait CompArithm: MapExt {
     {
         self.cjoin_assign(other, |a, b| *a = a.saturating_mul(b))
     }
+
+    /// Do componentwise wrapping subtraction.
+    fn wrapping_sub(
+        self,
+        other: impl Into<Self::This<Self::Val>>,
+    ) -> Self::This<Self::Val>
+    where
+        Self::Val: Wrapping,
+    {
+        self.cjoin(other, Self::Val::wrapping_sub)
+    }
+
+    /// Do componentwise wrapping subtraction in place.
+    fn wrapping_sub_assign(&mut self, other: impl Into<Self::This<Self::Val>>)
+    where
+        Self::Val: Wrapping + Copy,
+    {
+        self.cjoin_assign(other, |a, b| *a = a.wrapping_sub(b))
+    }
+
+    /// Do componentwise wrapping addition.
+    fn wrapping_add(
+        self,
+        other: impl Into<Self::This<Self::Val>>,
+    ) -> Self::This<Self::Val>
+    where
+        Self::Val: Wrapping,
+    {
+        self.cjoin(other, Self::Val::wrapping_add)
+    }
+
+    /// Do componentwise wrapping addition in place.
+    fn wrapping_add_assign(&mut self, other: impl Into<Self::This<Self::Val>>)
+    where
+        Self::Val: Wrapping + Copy,
+    {
+        self.cjoin_assign(other, |a, b| *a = a.wrapping_add(b))
+    }
+
+    /// Do componentwise wrapping multiplication.
+    fn wrapping_cmul(
+        self,
+        other: impl Into<Self::This<Self::Val>>,
+    ) -> Self::This<Self::Val>
+    where
+        Self::Val: Wrapping,
+    {
+        self.cjoin(other, Self::Val::wrapping_mul)
+    }
+
+    /// Do componentwise wrapping multiplication in place.
+    fn wrapping_cmul_assign(&mut self, other: impl Into<Self::This<Self::Val>>)
+    where
+        Self::Val: Wrapping + Copy,
+    {
+        self.cjoin_assign(other, |a, b| *a = a.wrapping_mul(b))
+    }
+
+    /// Do componentwise wrapping negation.
+    fn wrapping_neg(self) -> Self::This<Self::Val>
+    where
+        Self::Val: Wrapping,
+    {
+        self.map(Self::Val::wrapping_neg)
+    }
+
+    /// Do componentwise overflowing subtraction. Returns the wrapped result
+    /// and mask of components that overflowed.
+    fn overflowing_sub(
+        self,
+        other: impl Into<Self::This<Self::Val>>,
+    ) -> (Self::This<Self::Val>, Self::This<bool>)
+    where
+        Self::Val: Overflowing,
+        Self::This<(Self::Val, bool)>: Copy
+            + MapExt<
+                Val = (Self::Val, bool),
+                This<Self::Val> = Self::This<Self::Val>,
+            > + MapExt<Val = (Self::Val, bool), This<bool> = Self::This<bool>>,
+    {
+        let res = self.cjoin(other, |a, b| a.overflowing_sub(b));
+        (res.map(|a| a.0), res.map(|a| a.1))
+    }
+
+    /// Do componentwise overflowing addition. Returns the wrapped result and
+    /// mask of components that overflowed.
+    fn overflowing_add(
+        self,
+        other: impl Into<Self::This<Self::Val>>,
+    ) -> (Self::This<Self::Val>, Self::This<bool>)
+    where
+        Self::Val: Overflowing,
+        Self::This<(Self::Val, bool)>: Copy
+            + MapExt<
+                Val = (Self::Val, bool),
+                This<Self::Val> = Self::This<Self::Val>,
+            > + MapExt<Val = (Self::Val, bool), This<bool> = Self::This<bool>>,
+    {
+        let res = self.cjoin(other, |a, b| a.overflowing_add(b));
+        (res.map(|a| a.0), res.map(|a| a.1))
+    }
+
+    /// Do componentwise overflowing multiplication. Returns the wrapped result
+    /// and mask of components that overflowed.
+    fn overflowing_cmul(
+        self,
+        other: impl Into<Self::This<Self::Val>>,
+    ) -> (Self::This<Self::Val>, Self::This<bool>)
+    where
+        Self::Val: Overflowing,
+        Self::This<(Self::Val, bool)>: Copy
+            + MapExt<
+                Val = (Self::Val, bool),
+                This<Self::Val> = Self::This<Self::Val>,
+            > + MapExt<Val = (Self::Val, bool), This<bool> = Self::This<bool>>,
+    {
+        let res = self.cjoin(other, |a, b| a.overflowing_mul(b));
+        (res.map(|a| a.0), res.map(|a| a.1))
+    }
+
+    /// Do componentwise overflowing negation. Returns the wrapped result and
+    /// mask of components that overflowed.
+    fn overflowing_neg(self) -> (Self::This<Self::Val>, Self::This<bool>)
+    where
+        Self::Val: Overflowing,
+        Self::This<(Self::Val, bool)>: Copy
+            + MapExt<
+                Val = (Self::Val, bool),
+                This<Self::Val> = Self::This<Self::Val>,
+            > + MapExt<Val = (Self::Val, bool), This<bool> = Self::This<bool>>,
+    {
+        let res = self.map(|a| a.overflowing_neg());
+        (res.map(|a| a.0), res.map(|a| a.1))
+    }
+
+    /// Do componentwise euclidean division.
+    fn div_euclid(
+        self,
+        other: impl Into<Self::This<Self::Val>>,
+    ) -> Self::This<Self::Val>
+    where
+        Self::Val: Euclid,
+    {
+        self.cjoin(other, Self::Val::div_euclid)
+    }
+
+    /// Get componentwise remainder of euclidean division.
+    fn rem_euclid(
+        self,
+        other: impl Into<Self::This<Self::Val>>,
+    ) -> Self::This<Self::Val>
+    where
+        Self::Val: Euclid,
+    {
+        self.cjoin(other, Self::Val::rem_euclid)
+    }
+
+    /// Wrap the position so that it is within the given size. The result is
+    /// never negative.
+    fn wrap_into(
+        self,
+        size: impl Into<Self::This<Self::Val>>,
+    ) -> Self::This<Self::Val>
+    where
+        Self::Val: Euclid,
+    {
+        self.rem_euclid(size)
+    }
+
+    /// Split position into position of chunk and position within the chunk.
+    fn split_chunk(
+        self,
+        chunk_size: impl Into<Self::This<Self::Val>>,
+    ) -> (Self::This<Self::Val>, Self::This<Self::Val>)
+    where
+        Self: Copy,
+        Self::Val: Euclid,
+        Self::This<Self::Val>: Copy,
+    {
+        let size = chunk_size.into();
+        (self.div_euclid(size), self.rem_euclid(size))
+    }
+
+    /// Get the shortest difference from this position to the other position
+    /// in space that wraps around at the given size (torus). The components
+    /// of the result are in range `-size / 2..=size / 2`, so the type must
+    /// be signed.
+    fn torus_diff(
+        self,
+        other: impl Into<Self::This<Self::Val>>,
+        size: impl Into<Self::This<Self::Val>>,
+    ) -> Self::This<Self::Val>
+    where
+        Self::Val: Copy
+            + Sub<Output = Self::Val>
+            + Div<Output = Self::Val>
+            + Neg<Output = Self::Val>
+            + Euclid
+            + PartialOrd
+            + Two,
+        Self::This<Self::Val>: CompArithm<
+                Val = Self::Val,
+                This<Self::Val> = Self::This<Self::Val>,
+            >,
+    {
+        let d = self.cjoin(other, |a, b| b - a);
+        d.cjoin(size, |d, s| {
+            let d = d.rem_euclid(s);
+            if d > s / Self::Val::TWO { d - s } else { d }
+        })
+    }
+
+    /// Get the componentwise minimum.
+    fn cmin(
+        self,
+        other: impl Into<Self::This<Self::Val>>,
+    ) -> Self::This<Self::Val>
+    where
+        Self::Val: PartialOrd,
+    {
+        self.cjoin(other, |a, b| if b < a { b } else { a })
+    }
+
+    /// Get the componentwise maximum.
+    fn cmax(
+        self,
+        other: impl Into<Self::This<Self::Val>>,
+    ) -> Self::This<Self::Val>
+    where
+        Self::Val: PartialOrd,
+    {
+        self.cjoin(other, |a, b| if b > a { b } else { a })
+    }
+
+    /// Clamp each component to the range given by the corresponding
+    /// components of `min` and `max`.
+    fn cclamp(
+        self,
+        min: impl Into<Self::This<Self::Val>>,
+        max: impl Into<Self::This<Self::Val>>,
+    ) -> Self::This<Self::Val>
+    where
+        Self::Val: PartialOrd,
+        Self::This<Self::Val>: CompArithm<
+                Val = Self::Val,
+                This<Self::Val> = Self::This<Self::Val>,
+            >,
+    {
+        self.cmax(min).cmin(max)
+    }
 }
 
 impl<T> CompArithm for Vec2<T> {
@@ -209,3 +462,39 @@ impl<T> CompArithm for Vec4<T> {
         f(&mut self.w, o.w);
     }
 }
+
+impl<T> CompArithm for Rect<T> {
+    fn cjoin<R, O>(
+        self,
+        other: impl Into<Self::This<O>>,
+        f: impl FnMut(Self::Val, O) -> R,
+    ) -> Self::This<R> {
+        Rect(self.0.cjoin(other.into().0, f))
+    }
+
+    fn cjoin_assign<O>(
+        &mut self,
+        other: impl Into<Self::This<O>>,
+        f: impl FnMut(&mut Self::Val, O),
+    ) {
+        self.0.cjoin_assign(other.into().0, f)
+    }
+}
+
+impl<T> CompArithm for Rgba<T> {
+    fn cjoin<R, O>(
+        self,
+        other: impl Into<Self::This<O>>,
+        f: impl FnMut(Self::Val, O) -> R,
+    ) -> Self::This<R> {
+        Rgba(self.0.cjoin(other.into().0, f))
+    }
+
+    fn cjoin_assign<O>(
+        &mut self,
+        other: impl Into<Self::This<O>>,
+        f: impl FnMut(&mut Self::Val, O),
+    ) {
+        self.0.cjoin_assign(other.into().0, f)
+    }
+}
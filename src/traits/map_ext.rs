@@ -1,6 +1,9 @@
-use std::ops::Range;
+use core::ops::Range;
 
-use crate::{Cast, Scale, Vec2, Vec3, Vec4};
+use crate::{
+    Abs, Cast, FloatChecks, Rect, Round, Scale, Signum, TryCast, Vec2, Vec3,
+    Vec4,
+};
 
 /// Trait for types that can be mapped.
 pub trait MapExt: Sized {
@@ -26,6 +29,23 @@ pub trait MapExt: Sized {
         self.map(|a| a.cast())
     }
 
+    /// Cast the values to the given type. Returns [`None`] if any of the
+    /// values is not finite or doesn't fit into the other type.
+    fn try_cast<R>(self) -> Option<Self::This<R>>
+    where
+        Self::Val: TryCast<R>,
+        Self::This<Option<R>>:
+            MapExt<Val = Option<R>, This<R> = Self::This<R>>,
+    {
+        let mut ok = true;
+        let res = self.map(|a| {
+            let r = a.try_cast();
+            ok &= r.is_some();
+            r
+        });
+        ok.then(|| res.map(|a| a.unwrap()))
+    }
+
     /// Scale the components to the given type. The components are scaled with
     /// their normal range. For floats this range is 0 to 1 and for ints it is
     /// their full range.
@@ -35,6 +55,89 @@ pub trait MapExt: Sized {
     {
         self.map(|a| a.scale())
     }
+
+    /// Round the components towards negative infinity.
+    fn floor(self) -> Self::This<Self::Val>
+    where
+        Self::Val: Round,
+    {
+        self.map(Self::Val::floor)
+    }
+
+    /// Round the components towards positive infinity.
+    fn ceil(self) -> Self::This<Self::Val>
+    where
+        Self::Val: Round,
+    {
+        self.map(Self::Val::ceil)
+    }
+
+    /// Round the components to the nearest integer.
+    fn round(self) -> Self::This<Self::Val>
+    where
+        Self::Val: Round,
+    {
+        self.map(Self::Val::round)
+    }
+
+    /// Round the components towards zero.
+    fn trunc(self) -> Self::This<Self::Val>
+    where
+        Self::Val: Round,
+    {
+        self.map(Self::Val::trunc)
+    }
+
+    /// Get the fractional part of the components.
+    fn fract(self) -> Self::This<Self::Val>
+    where
+        Self::Val: Round,
+    {
+        self.map(Self::Val::fract)
+    }
+
+    /// Get the absolute value of the components.
+    fn abs(self) -> Self::This<Self::Val>
+    where
+        Self::Val: Abs,
+    {
+        self.map(Self::Val::abs)
+    }
+
+    /// Get the sign of the components.
+    fn signum(self) -> Self::This<Self::Val>
+    where
+        Self::Val: Signum,
+    {
+        self.map(Self::Val::signum)
+    }
+
+    /// Check which of the components are finite.
+    fn is_finite(&self) -> Self::This<bool>
+    where
+        Self: Clone,
+        Self::Val: FloatChecks,
+    {
+        self.clone().map(|a| a.is_finite())
+    }
+
+    /// Check which of the components are infinite.
+    fn is_infinite(&self) -> Self::This<bool>
+    where
+        Self: Clone,
+        Self::Val: FloatChecks,
+    {
+        self.clone().map(|a| a.is_infinite())
+    }
+
+    /// Check which of the components are NaN.
+    fn is_nan(&self) -> Self::This<bool>
+    where
+        Self: Clone,
+        Self::Val: FloatChecks,
+    {
+        self.clone().map(|a| a.is_nan())
+    }
 }
 
 impl<T> MapExt for Vec2<T> {
@@ -81,3 +184,12 @@ impl<T> MapExt for Vec4<T> {
         Vec4::new(f(self.x), f(self.y), f(self.z), f(self.w))
     }
 }
+
+impl<T> MapExt for Rect<T> {
+    type Val = T;
+    type This<R> = Rect<R>;
+
+    fn map<R>(self, f: impl FnMut(Self::Val) -> R) -> Self::This<R> {
+        Rect(self.0.map(f))
+    }
+}
@@ -1,3 +1,4 @@
+mod approx_eq;
 mod comp_arithm;
 mod map_ext;
 mod range_ext;
@@ -5,5 +6,6 @@ mod rect_ext;
 mod two_component;
 
 pub use self::{
-    comp_arithm::*, map_ext::*, range_ext::*, rect_ext::*, two_component::*,
+    approx_eq::*, comp_arithm::*, map_ext::*, range_ext::*, rect_ext::*,
+    two_component::*,
 };
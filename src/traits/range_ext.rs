@@ -1,4 +1,4 @@
-use std::ops::{Range, Sub};
+use core::ops::{Range, Sub};
 
 use crate::{TwoComponent, Vec2};
 
@@ -162,7 +162,7 @@ pub trait RangeExt: Sized + TwoComponent {
             // Self is after
             if s1 > e2 {
                 // No overlap
-                (Self::from_components(s1, e2), None)
+                (Self::from_components(s1, e1), None)
             } else {
                 // Overlap
                 (Self::from_components(e2, e1), None)
@@ -201,7 +201,7 @@ pub trait RangeExt: Sized + TwoComponent {
             // Self is after
             if s1 > e2 {
                 // No overlap
-                Self::from_components(s1, e2)
+                Self::from_components(s1, e1)
             } else {
                 // Overlap
                 Self::from_components(e2, e1)
@@ -266,3 +266,26 @@ pub trait RangeExt: Sized + TwoComponent {
 
 impl<T> RangeExt for Vec2<T> {}
 impl<T> RangeExt for Range<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sub_range_before() {
+        // The subtracted range is entirely before self.
+        assert_eq!((5..10).sub_range(1..3), (5..10, None));
+        assert_eq!((5..10).sub_range_gap(1..3), 5..10);
+    }
+
+    #[test]
+    fn sub_range_cases() {
+        assert_eq!((5..10).sub_range(12..15), (5..10, None));
+        assert_eq!((5..10).sub_range(7..8), (5..7, Some(8..10)));
+        assert_eq!((5..10).sub_range(7..15), (5..7, None));
+        assert_eq!((5..10).sub_range(1..7), (7..10, None));
+        assert_eq!((5..10).sub_range_gap(7..8), 5..10);
+        assert_eq!((5..10).sub_range_gap(7..15), 5..7);
+        assert_eq!((5..10).sub_range_gap(1..7), 7..10);
+    }
+}
@@ -1,6 +1,9 @@
-use std::ops::{Add, Div, Mul, Range, Sub};
+use core::ops::{Add, Div, Mul, Range, Sub};
 
-use crate::{CompArithm, Padding, RangeExt, Two, Vec2, Zero};
+use crate::{
+    Align, Axis, Cast, CompArithm, Fit, MapExt, One, Padding, RangeExt, Two,
+    Vec2, Vec2RangeIter, Zero, layout::snap,
+};
 
 pub trait RectExt: Sized {
     type Val: Copy
@@ -274,6 +277,94 @@ pub trait RectExt: Sized {
         self.set_height(b - self.top());
     }
 
+    /// Iterate over positions of all the cells in the rectangle.
+    fn positions(&self) -> Vec2RangeIter<Self::Val>
+    where
+        Self::Val: Div<Output = Self::Val>
+            + Mul<Output = Self::Val>
+            + One
+            + Cast<usize>,
+        usize: Cast<Self::Val>,
+    {
+        Vec2RangeIter::from_rect(self)
+    }
+
+    /// Place rectangle with the given size within this rectangle with the
+    /// given alignment in each of the axes. For integer types the position is
+    /// rounded to the nearest integer.
+    fn place(
+        &self,
+        size: impl Into<Vec2<Self::Val>>,
+        align: impl Into<Vec2<Align>>,
+    ) -> Self
+    where
+        Self::Val: Cast<f64>,
+        f64: Cast<Self::Val>,
+    {
+        let mut size = size.into();
+        let align = align.into();
+        let space = self.size();
+        let mut pos = self.pos();
+        for axis in [Axis::X, Axis::Y] {
+            let free = space[axis].cast() - size[axis].cast();
+            let off = match align[axis] {
+                Align::Start => 0.,
+                Align::Center => free / 2.,
+                Align::End => free,
+                Align::Stretch => {
+                    size[axis] = space[axis];
+                    0.
+                }
+            };
+            pos[axis] = snap(pos[axis].cast() + off);
+        }
+        Self::from_pos_size(pos, size)
+    }
+
+    /// Scale the given size to fit into this rectangle and center it. For
+    /// integer types the size and position are rounded to the nearest
+    /// integer.
+    fn fit(&self, size: impl Into<Vec2<Self::Val>>, mode: Fit) -> Self
+    where
+        Self::Val: Cast<f64>,
+        f64: Cast<Self::Val>,
+    {
+        let size = size.into().cast::<f64>();
+        let space = self.size().cast::<f64>();
+        let size = if mode == Fit::Fill {
+            space
+        } else {
+            let f = mode.factor(size, space);
+            if f.is_finite() {
+                size * f
+            } else {
+                Vec2::new(0., 0.)
+            }
+        };
+        self.place(size.map(snap), (Align::Center, Align::Center))
+    }
+
+    /// Scale the rectangle by the given factor so that the pivot stays at the
+    /// same place. For integer types the edges are rounded to the nearest
+    /// integer.
+    fn scale_about(
+        &self,
+        pivot: impl Into<Vec2<Self::Val>>,
+        factor: f64,
+    ) -> Self
+    where
+        Self::Val: Cast<f64>,
+        f64: Cast<Self::Val>,
+    {
+        let pivot = pivot.into().cast::<f64>();
+        let scale =
+            |p: Vec2<Self::Val>| (p.cast::<f64>() - pivot) * factor + pivot;
+        Self::from_points(
+            scale(self.top_left()).map(snap),
+            scale(self.bot_right()).map(snap),
+        )
+    }
+
     /// Set the top value.
     fn set_top(&mut self, t: Self::Val) {
         self.set_width(self.bottom() - t);
@@ -0,0 +1,283 @@
+use core::{
+    fmt::Debug,
+    hash::Hash,
+    marker::PhantomData,
+    ops::{
+        Add, AddAssign, Deref, Div, DivAssign, Mul, MulAssign, Neg, Sub,
+        SubAssign,
+    },
+};
+
+use crate::{One, Padding, Rect, RectExt, Vec2, Vec3, Vec4};
+
+/// Value tagged with the coordinate space `S` it is in. Arithmetic is
+/// allowed only between values in the same space. The tag has no runtime
+/// cost.
+///
+/// The space is usually empty type such as `enum Screen {}`. Values may be
+/// moved to other space with [`ScaleFactor`] or explicitly with
+/// [`Typed::cast_unit`].
+#[repr(transparent)]
+pub struct Typed<V, S> {
+    value: V,
+    space: PhantomData<S>,
+}
+
+/// 2D vector in the coordinate space `S`.
+pub type TypedVec2<T, S> = Typed<Vec2<T>, S>;
+/// 3D vector in the coordinate space `S`.
+pub type TypedVec3<T, S> = Typed<Vec3<T>, S>;
+/// Rectangle in the coordinate space `S`.
+pub type TypedRect<T, S> = Typed<Rect<T>, S>;
+
+impl<V, S> Typed<V, S> {
+    /// Tag the value with the coordinate space.
+    pub const fn new(value: V) -> Self {
+        Self {
+            value,
+            space: PhantomData,
+        }
+    }
+
+    /// Get the untyped value.
+    pub fn untyped(self) -> V {
+        self.value
+    }
+
+    /// Reinterpret the value as if it was in other coordinate space.
+    pub fn cast_unit<D>(self) -> Typed<V, D> {
+        Typed::new(self.value)
+    }
+
+    /// Apply function to the untyped value and keep the space.
+    pub fn map<R>(self, f: impl FnOnce(V) -> R) -> Typed<R, S> {
+        Typed::new(f(self.value))
+    }
+}
+
+impl<T, S> Typed<Rect<T>, S>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + PartialOrd,
+{
+    /// Create rectangle from position and size in the same space.
+    pub fn from_pos_size(pos: TypedVec2<T, S>, size: TypedVec2<T, S>) -> Self {
+        Self::new(Rect::from_pos_size(pos.value, size.value))
+    }
+
+    /// Get the position of the top left corner.
+    pub fn pos(&self) -> TypedVec2<T, S> {
+        Typed::new(self.value.pos())
+    }
+
+    /// Get the size.
+    pub fn size(&self) -> TypedVec2<T, S> {
+        Typed::new(self.value.size())
+    }
+
+    /// Check whether the point in the same space is inside the rectangle.
+    pub fn contains(&self, point: TypedVec2<T, S>) -> bool {
+        self.value.contains(point.value)
+    }
+}
+
+impl<V: Clone, S> Clone for Typed<V, S> {
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<V: Copy, S> Copy for Typed<V, S> {}
+
+impl<V: Default, S> Default for Typed<V, S> {
+    fn default() -> Self {
+        Self::new(V::default())
+    }
+}
+
+impl<V: Debug, S> Debug for Typed<V, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Typed")
+            .field(&self.value)
+            .field(&core::any::type_name::<S>())
+            .finish()
+    }
+}
+
+impl<V: PartialEq, S> PartialEq for Typed<V, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<V: Eq, S> Eq for Typed<V, S> {}
+
+impl<V: Hash, S> Hash for Typed<V, S> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl<V, S> Deref for Typed<V, S> {
+    type Target = V;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<V: Neg, S> Neg for Typed<V, S> {
+    type Output = Typed<V::Output, S>;
+
+    fn neg(self) -> Self::Output {
+        Typed::new(-self.value)
+    }
+}
+
+macro_rules! op_typed {
+    ($op:ident, $fn:ident, $op_assign:ident, $fn_assign:ident) => {
+        impl<L, R, S> $op<Typed<R, S>> for Typed<L, S>
+        where
+            L: $op<R>,
+        {
+            type Output = Typed<L::Output, S>;
+
+            fn $fn(self, rhs: Typed<R, S>) -> Self::Output {
+                Typed::new(self.value.$fn(rhs.value))
+            }
+        }
+
+        impl<L, R, S> $op_assign<Typed<R, S>> for Typed<L, S>
+        where
+            L: $op_assign<R>,
+        {
+            fn $fn_assign(&mut self, rhs: Typed<R, S>) {
+                self.value.$fn_assign(rhs.value);
+            }
+        }
+    };
+}
+
+op_typed!(Add, add, AddAssign, add_assign);
+op_typed!(Sub, sub, SubAssign, sub_assign);
+
+macro_rules! op_typed_scalar {
+    (
+        $op:ident, $fn:ident, $op_assign:ident, $fn_assign:ident;
+        $($t:ident $(.$f:tt)?),*
+    ) => {
+        $(
+            impl<T, S> $op<T> for Typed<$t<T>, S>
+            where
+                T: $op<T, Output = T> + Copy,
+            {
+                type Output = Self;
+
+                fn $fn(self, rhs: T) -> Self::Output {
+                    self.map(|mut v| {
+                        v$(.$f)? = v$(.$f)?.$fn(rhs);
+                        v
+                    })
+                }
+            }
+
+            impl<T, S> $op_assign<T> for Typed<$t<T>, S>
+            where
+                T: $op_assign<T> + Copy,
+            {
+                fn $fn_assign(&mut self, rhs: T) {
+                    self.value$(.$f)?.$fn_assign(rhs);
+                }
+            }
+        )*
+    };
+}
+
+op_typed_scalar!(Mul, mul, MulAssign, mul_assign; Vec2, Vec3, Vec4, Rect.0);
+op_typed_scalar!(Div, div, DivAssign, div_assign; Vec2, Vec3, Vec4, Rect.0);
+
+/// Conversion factor from the coordinate space `Src` to the coordinate space
+/// `Dst`. Multiply typed value by this to move it to the other space.
+#[repr(transparent)]
+pub struct ScaleFactor<T, Src, Dst> {
+    factor: T,
+    space: PhantomData<(Src, Dst)>,
+}
+
+impl<T, Src, Dst> ScaleFactor<T, Src, Dst> {
+    /// Create conversion factor such that `dst = src * factor`.
+    pub const fn new(factor: T) -> Self {
+        Self {
+            factor,
+            space: PhantomData,
+        }
+    }
+
+    /// Get the factor.
+    pub fn get(self) -> T {
+        self.factor
+    }
+
+    /// Get the conversion factor in the opposite direction.
+    pub fn inverse(self) -> ScaleFactor<T, Dst, Src>
+    where
+        T: One + Div<Output = T>,
+    {
+        ScaleFactor::new(T::ONE / self.factor)
+    }
+
+    /// Chain this conversion with conversion from `Dst` to `D`.
+    pub fn then<D>(
+        self,
+        other: ScaleFactor<T, Dst, D>,
+    ) -> ScaleFactor<T, Src, D>
+    where
+        T: Mul<Output = T>,
+    {
+        ScaleFactor::new(self.factor * other.factor)
+    }
+}
+
+impl<T: Clone, Src, Dst> Clone for ScaleFactor<T, Src, Dst> {
+    fn clone(&self) -> Self {
+        Self::new(self.factor.clone())
+    }
+}
+
+impl<T: Copy, Src, Dst> Copy for ScaleFactor<T, Src, Dst> {}
+
+impl<T: Debug, Src, Dst> Debug for ScaleFactor<T, Src, Dst> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("ScaleFactor")
+            .field(&self.factor)
+            .field(&core::any::type_name::<Src>())
+            .field(&core::any::type_name::<Dst>())
+            .finish()
+    }
+}
+
+impl<T: PartialEq, Src, Dst> PartialEq for ScaleFactor<T, Src, Dst> {
+    fn eq(&self, other: &Self) -> bool {
+        self.factor == other.factor
+    }
+}
+
+macro_rules! impl_scale_factor {
+    ($($t:ident $(.$f:tt)?),*) => {
+        $(impl<T, Src, Dst> Mul<Typed<$t<T>, Src>> for ScaleFactor<T, Src, Dst>
+        where
+            T: Mul<Output = T> + Copy,
+        {
+            type Output = Typed<$t<T>, Dst>;
+
+            fn mul(self, rhs: Typed<$t<T>, Src>) -> Self::Output {
+                rhs.map(|mut v| {
+                    v$(.$f)? = v$(.$f)? * self.factor;
+                    v
+                })
+                .cast_unit()
+            }
+        })*
+    };
+}
+
+impl_scale_factor!(Vec2, Vec3, Vec4, Rect.0, Padding.0);
@@ -1,23 +1,31 @@
-use std::{
+use core::{
     cmp::Ordering,
     fmt::Display,
+    hash::Hash,
     ops::{
         Add, AddAssign, Bound, Div, DivAssign, Index, IndexMut, Mul,
         MulAssign, Neg, Not, Range, RangeBounds, Rem, RemAssign, Sub,
         SubAssign,
     },
+    slice,
 };
 
+#[cfg(feature = "alloc")]
+use crate::CurveIter;
+#[cfg(feature = "alloc")]
+use crate::FloodFill;
 use crate::{
-    Checked, Float, Goniometric, IntoFloat, Isqrt, LargeType, MapExt,
-    NormalLimits, Scale, Sqrt, Vec2RangeIter, Zero,
+    Cast, Checked, Curve, Goniometric, Hilbert, IntoFloat, Isqrt, LargeType,
+    MapExt, Metric, Morton, NormalLimits, One, Rad, Scale, Sqrt,
+    Vec2RangeIter, Vec2RingIter, Zero,
 };
 
 /// Represents two dimensional vector. Can be used as vector, point, size or
 /// any tuple-like object where vector math operations are benefit.
 ///
 /// It is meant to be as convinient as possible to work with in many use cases.
-#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
 pub struct Vec2<T = usize> {
     /// The first coordinate of the vector (x, w, [0]).
     pub x: T,
@@ -31,6 +39,46 @@ impl<T> Vec2<T> {
         Self { x, y }
     }
 
+    /// Reinterpret slice of vectors as slice of their components.
+    pub fn as_slice(v: &[Self]) -> &[T] {
+        // SAFETY: `Vec2` is `repr(C)` with 2 fields of type `T`, so it has
+        // the same layout as `[T; 2]`.
+        unsafe { slice::from_raw_parts(v.as_ptr().cast(), v.len() * 2) }
+    }
+
+    /// Reinterpret mutable slice of vectors as slice of their components.
+    pub fn as_mut_slice(v: &mut [Self]) -> &mut [T] {
+        // SAFETY: `Vec2` is `repr(C)` with 2 fields of type `T`, so it has
+        // the same layout as `[T; 2]`.
+        unsafe {
+            slice::from_raw_parts_mut(v.as_mut_ptr().cast(), v.len() * 2)
+        }
+    }
+
+    /// Reinterpret slice of components as slice of vectors. Returns
+    /// [`None`] if the length of the slice is not multiple of 2.
+    pub fn from_slice(s: &[T]) -> Option<&[Self]> {
+        if s.len() % 2 != 0 {
+            return None;
+        }
+        // SAFETY: `Vec2` is `repr(C)` with 2 fields of type `T`, so it has
+        // the same layout as `[T; 2]`.
+        Some(unsafe { slice::from_raw_parts(s.as_ptr().cast(), s.len() / 2) })
+    }
+
+    /// Reinterpret mutable slice of components as slice of vectors. Returns
+    /// [`None`] if the length of the slice is not multiple of 2.
+    pub fn from_mut_slice(s: &mut [T]) -> Option<&mut [Self]> {
+        if s.len() % 2 != 0 {
+            return None;
+        }
+        // SAFETY: `Vec2` is `repr(C)` with 2 fields of type `T`, so it has
+        // the same layout as `[T; 2]`.
+        Some(unsafe {
+            slice::from_raw_parts_mut(s.as_mut_ptr().cast(), s.len() / 2)
+        })
+    }
+
     /// Get the width. Alias to the first coordinate (x, [0])
     pub fn w(&self) -> &T {
         &self.x
@@ -300,20 +348,20 @@ impl<T> Vec2<T> {
     }
 
     /// Iterate over the two components.
-    pub fn iter(&self) -> std::array::IntoIter<&T, 2> {
+    pub fn iter(&self) -> core::array::IntoIter<&T, 2> {
         let r: [_; 2] = self.as_ref().into();
         r.into_iter()
     }
 
     /// Get mutable iterator over the two components.
-    pub fn iter_mut(&mut self) -> std::array::IntoIter<&mut T, 2> {
+    pub fn iter_mut(&mut self) -> core::array::IntoIter<&mut T, 2> {
         let r: [_; 2] = self.as_mut().into();
         r.into_iter()
     }
 
     /// Swaps the two components.
     pub fn swap(&mut self) {
-        std::mem::swap(&mut self.x, &mut self.y);
+        core::mem::swap(&mut self.x, &mut self.y);
     }
 
     /// Swaps the two components.
@@ -440,6 +488,30 @@ impl<T> Vec2<T> {
         (self.len(), self.angle())
     }
 
+    /// Get the angle of the vector as [`Rad`].
+    pub fn angle_rad(self) -> Rad<T::Output>
+    where
+        T: Goniometric,
+    {
+        Rad(self.angle())
+    }
+
+    /// Calculate the polar coordinates of the vector with the angle as
+    /// [`Rad`].
+    pub fn polar_rad(
+        self,
+    ) -> (
+        <<<T as Mul>::Output as Add>::Output as Sqrt>::Output,
+        Rad<<T as Goniometric>::Output>,
+    )
+    where
+        T: Copy + Mul + Goniometric,
+        <T as Mul>::Output: Add,
+        <<T as Mul>::Output as Add>::Output: Sqrt,
+    {
+        (self.len(), self.angle_rad())
+    }
+
     /// Gets normalized version of the vector as float.
     #[allow(clippy::type_complexity)]
     pub fn normalized(
@@ -466,7 +538,7 @@ impl<T> Vec2<T> {
     /// Normalizes this vector.
     pub fn normalize(&mut self)
     where
-        T: Copy + Float + Mul,
+        T: Copy + Mul,
         T::Output: Add<T::Output>,
         <T::Output as Add>::Output: Sqrt,
         <<T::Output as Add>::Output as Sqrt>::Output: Copy,
@@ -478,7 +550,14 @@ impl<T> Vec2<T> {
     /// Craetes range from this vector to the other vector.
     pub fn to(self, other: impl Into<Vec2<T>>) -> Vec2RangeIter<T>
     where
-        T: Copy,
+        T: Copy
+            + Sub<Output = T>
+            + Div<Output = T>
+            + Mul<Output = T>
+            + PartialOrd
+            + One
+            + Cast<usize>,
+        usize: Cast<T>,
     {
         Vec2RangeIter::new(self, other.into())
     }
@@ -486,7 +565,7 @@ impl<T> Vec2<T> {
     /// Creates vector from polar coordinates.
     pub fn from_polar<L, A>(length: L, angle: A) -> Self
     where
-        A: Copy + Float + Goniometric,
+        A: Copy + Goniometric,
         A::Output: Mul<L, Output = T>,
         L: Copy,
     {
@@ -579,6 +658,58 @@ impl<T> Vec2<T> {
             if s.y { o.y } else { self.y },
         )
     }
+
+    /// Iterate over positions at distance `radius` from this position
+    /// measured with the given `metric`. Only positions inside 2D space of
+    /// size `bounds` are produced, so this never underflows for unsigned
+    /// types. If stepping along the ring overflows `T`, the iteration ends
+    /// early and the rest of the ring is silently skipped.
+    pub fn ring(
+        self,
+        radius: T,
+        metric: Metric,
+        bounds: impl Into<Vec2<T>>,
+    ) -> Vec2RingIter<T>
+    where
+        T: Copy + Checked + One + Zero + PartialEq,
+    {
+        Vec2RingIter::new(self, radius, metric, bounds.into())
+    }
+
+    /// Iterate over the 4 neighbours (sharing side) of this position that are
+    /// inside 2D space of size `bounds`.
+    pub fn neighbors4(self, bounds: impl Into<Vec2<T>>) -> Vec2RingIter<T>
+    where
+        T: Copy + Checked + One + Zero + PartialEq,
+    {
+        self.ring(T::ONE, Metric::Manhattan, bounds)
+    }
+
+    /// Iterate over the 8 neighbours (sharing side or corner) of this
+    /// position that are inside 2D space of size `bounds`.
+    pub fn neighbors8(self, bounds: impl Into<Vec2<T>>) -> Vec2RingIter<T>
+    where
+        T: Copy + Checked + One + Zero + PartialEq,
+    {
+        self.ring(T::ONE, Metric::Chebyshev, bounds)
+    }
+
+    /// Iterate over all positions connected to this position that satisfy
+    /// the predicate in breadth first order. Neighbouring positions are at
+    /// distance 1 with the given `metric`.
+    #[cfg(feature = "alloc")]
+    pub fn flood_fill<F>(
+        self,
+        bounds: impl Into<Vec2<T>>,
+        metric: Metric,
+        pred: F,
+    ) -> FloodFill<T, F>
+    where
+        T: Copy + Checked + One + Zero + Ord,
+        F: FnMut(Vec2<T>) -> bool,
+    {
+        FloodFill::new(self, bounds.into(), metric, pred)
+    }
 }
 
 impl Vec2<bool> {
@@ -603,6 +734,45 @@ impl Vec2<bool> {
     }
 }
 
+impl Vec2<u32> {
+    /// Get the index of this position on Morton curve (Z-order).
+    pub fn morton(self) -> u64 {
+        Morton::encode(self)
+    }
+
+    /// Get the position at the given index on Morton curve (Z-order).
+    pub fn from_morton(d: u64) -> Self {
+        Morton::decode(d)
+    }
+
+    /// Get the index of this position on Hilbert curve.
+    pub fn hilbert(self) -> u64 {
+        Hilbert::encode(self)
+    }
+
+    /// Get the position at the given index on Hilbert curve.
+    pub fn from_hilbert(d: u64) -> Self {
+        Hilbert::decode(d)
+    }
+
+    /// Creates range from this vector to the other vector that iterates in
+    /// Z-order.
+    #[cfg(feature = "alloc")]
+    pub fn z_order_to(self, other: impl Into<Vec2<u32>>) -> CurveIter<Morton> {
+        CurveIter::new(self, other.into())
+    }
+
+    /// Creates range from this vector to the other vector that iterates in
+    /// the order of Hilbert curve.
+    #[cfg(feature = "alloc")]
+    pub fn hilbert_to(
+        self,
+        other: impl Into<Vec2<u32>>,
+    ) -> CurveIter<Hilbert> {
+        CurveIter::new(self, other.into())
+    }
+}
+
 impl<T> Vec2<&T> {
     /// Clones the values in references of the vector.
     pub fn cloned(self) -> Vec2<T>
@@ -722,14 +892,14 @@ impl<T> Display for Vec2<T>
 where
     T: Display,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "[{}, {}]", self.x, self.y)
     }
 }
 
 impl<T> IntoIterator for Vec2<T> {
     type Item = T;
-    type IntoIter = std::array::IntoIter<T, 2>;
+    type IntoIter = core::array::IntoIter<T, 2>;
 
     fn into_iter(self) -> Self::IntoIter {
         let r: [_; 2] = self.into();
@@ -739,7 +909,7 @@ impl<T> IntoIterator for Vec2<T> {
 
 impl<'a, T> IntoIterator for &'a Vec2<T> {
     type Item = &'a T;
-    type IntoIter = std::array::IntoIter<&'a T, 2>;
+    type IntoIter = core::array::IntoIter<&'a T, 2>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
@@ -748,7 +918,7 @@ impl<'a, T> IntoIterator for &'a Vec2<T> {
 
 impl<'a, T> IntoIterator for &'a mut Vec2<T> {
     type Item = &'a mut T;
-    type IntoIter = std::array::IntoIter<&'a mut T, 2>;
+    type IntoIter = core::array::IntoIter<&'a mut T, 2>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.iter_mut()
@@ -778,7 +948,7 @@ where
 }
 
 impl<T: PartialOrd> PartialOrd for Vec2<T> {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         match (self.x.partial_cmp(&other.x)?, self.y.partial_cmp(&other.y)?) {
             (Ordering::Equal, Ordering::Equal) => Some(Ordering::Equal),
             (
@@ -1,63 +1,230 @@
-use std::ops::AddAssign;
+use core::{
+    iter::FusedIterator,
+    ops::{Add, Div, Mul, Rem, Sub},
+};
 
-use crate::{One, Vec2};
+use crate::{Cast, CompArithm, MapExt, One, RectExt, Vec2, Zero};
+
+/// Get the number of steps of size `step` needed to get from `start` to
+/// `end`.
+pub(crate) fn step_cnt<T>(start: T, end: T, step: T) -> usize
+where
+    T: Copy
+        + Sub<Output = T>
+        + Div<Output = T>
+        + Mul<Output = T>
+        + PartialOrd
+        + Cast<usize>,
+    usize: Cast<T>,
+{
+    if end <= start {
+        return 0;
+    }
+
+    let size = end - start;
+    let cnt: usize = (size / step).cast();
+    if cnt.cast() * step < size {
+        cnt + 1
+    } else {
+        cnt
+    }
+}
 
 /// Iterator over 2D area.
+///
+/// By default the area is iterated by rows (x changes fastest).
+#[derive(Debug, Clone)]
 pub struct Vec2RangeIter<T> {
     start: Vec2<T>,
-    end: Vec2<T>,
-    x: T,
+    step: Vec2<T>,
+    size: Vec2<usize>,
+    col_major: bool,
+    front: usize,
+    back: usize,
 }
 
 impl<T> Vec2RangeIter<T>
 where
-    T: Copy,
+    T: Copy
+        + Sub<Output = T>
+        + Div<Output = T>
+        + Mul<Output = T>
+        + PartialOrd
+        + Cast<usize>,
+    usize: Cast<T>,
 {
     /// Create new iterator over 2D area.
-    pub fn new(start: Vec2<T>, end: Vec2<T>) -> Self {
+    /// Panics if the number of positions doesn't fit into `usize`.
+    pub fn new(start: Vec2<T>, end: Vec2<T>) -> Self
+    where
+        T: One,
+    {
+        Self::stepped(start, end, Vec2::new(T::ONE, T::ONE))
+    }
+
+    /// Create new iterator over all the cells of the rectangle.
+    pub fn from_rect(rect: &impl RectExt<Val = T>) -> Self
+    where
+        T: One,
+    {
+        Self::new(rect.top_left(), rect.bot_right())
+    }
+
+    /// Create new iterator over 2D area that moves with the given step.
+    /// Panics if any component of the step is not positive or if the number
+    /// of positions doesn't fit into `usize`.
+    pub fn with_step(start: Vec2<T>, end: Vec2<T>, step: Vec2<T>) -> Self
+    where
+        T: Zero,
+    {
+        assert!(
+            step.x > T::ZERO && step.y > T::ZERO,
+            "Step must be positive."
+        );
+        Self::stepped(start, end, step)
+    }
+
+    fn stepped(start: Vec2<T>, end: Vec2<T>, step: Vec2<T>) -> Self {
+        let size = Vec2::new(
+            step_cnt(start.x, end.x, step.x),
+            step_cnt(start.y, end.y, step.y),
+        );
         Self {
-            x: start.x,
             start,
-            end,
+            step,
+            front: 0,
+            back: size.x.checked_mul(size.y).expect("Too many positions."),
+            size,
+            col_major: false,
         }
     }
 }
 
 impl<T> Vec2RangeIter<T> {
+    /// Iterate by columns (y changes fastest) instead of rows. This should be
+    /// called before the iteration starts.
+    pub fn col_major(mut self) -> Self {
+        self.col_major = true;
+        self
+    }
+
+    fn idx_pos(&self, i: usize) -> Vec2<usize> {
+        if self.col_major {
+            Vec2::new(i / self.size.y, i % self.size.y)
+        } else {
+            Vec2::new(i % self.size.x, i / self.size.x)
+        }
+    }
+
+    fn get(&self, i: usize) -> Vec2<T>
+    where
+        T: Copy + Add<Output = T> + Mul<Output = T>,
+        usize: Cast<T>,
+    {
+        self.start + self.idx_pos(i).cast().cmul(self.step)
+    }
+
     /// Check whether the remaining part contains te given vector.
     pub fn contains(&self, other: impl Into<Vec2<T>>) -> bool
     where
-        T: Ord,
+        T: Copy
+            + Sub<Output = T>
+            + Div<Output = T>
+            + Rem<Output = T>
+            + PartialOrd
+            + Zero
+            + Cast<usize>,
     {
-        let Vec2 { x, y } = other.into();
-        x >= self.start.x
-            && x < self.end.x
-            && y >= self.start.y
-            && y < self.end.y
+        let pos = other.into();
+        if pos.x < self.start.x || pos.y < self.start.y {
+            return false;
+        }
+
+        let off = pos - self.start;
+        if off.crem(self.step).is_any(|a| *a != T::ZERO) {
+            return false;
+        }
+
+        let idx: Vec2<usize> = off.cdiv(self.step).cast();
+        if idx.x >= self.size.x || idx.y >= self.size.y {
+            return false;
+        }
+
+        let i = if self.col_major {
+            idx.x * self.size.y + idx.y
+        } else {
+            self.size.x * idx.y + idx.x
+        };
+        (self.front..self.back).contains(&i)
     }
 }
 
 impl<T> Iterator for Vec2RangeIter<T>
 where
-    T: Copy + AddAssign + PartialOrd + One,
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+    usize: Cast<T>,
 {
     type Item = Vec2<T>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.x >= self.end.x {
-            if self.x == self.start.x {
-                return None;
-            }
-            self.x = self.start.x;
-            self.start.y += T::ONE;
+        if self.front >= self.back {
+            return None;
         }
 
-        if self.start.y >= self.end.y {
+        let res = self.get(self.front);
+        self.front += 1;
+        Some(res)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
+    }
+}
+
+impl<T> DoubleEndedIterator for Vec2RangeIter<T>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+    usize: Cast<T>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
             return None;
         }
 
-        let res = (self.x, self.start.y);
-        self.x += T::ONE;
-        Some(res.into())
+        self.back -= 1;
+        Some(self.get(self.back))
     }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.back = self.back.saturating_sub(n).max(self.front);
+        self.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for Vec2RangeIter<T>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+    usize: Cast<T>,
+{
+}
+
+impl<T> FusedIterator for Vec2RangeIter<T>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+    usize: Cast<T>,
+{
 }
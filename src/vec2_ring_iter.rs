@@ -0,0 +1,291 @@
+use crate::{Checked, MapExt, One, Vec2, Zero};
+
+/// Metric used to measure distance on a grid.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Metric {
+    /// Distance is the sum of the absolute differences of the components.
+    /// Neighbours at distance 1 share a side.
+    Manhattan,
+    /// Distance is the maximum of the absolute differences of the components.
+    /// Neighbours at distance 1 share a side or a corner.
+    Chebyshev,
+}
+
+/// Sides of the rings. Each side is given by its direction and the corner
+/// where it ends (in multiples of the radius).
+type Sides = [(Vec2<i8>, Vec2<i8>); 4];
+
+const MANHATTAN_SIDES: Sides = [
+    (Vec2::new(1, 1), Vec2::new(1, 0)),
+    (Vec2::new(-1, 1), Vec2::new(0, 1)),
+    (Vec2::new(-1, -1), Vec2::new(-1, 0)),
+    (Vec2::new(1, -1), Vec2::new(0, -1)),
+];
+
+const CHEBYSHEV_SIDES: Sides = [
+    (Vec2::new(1, 0), Vec2::new(1, -1)),
+    (Vec2::new(0, 1), Vec2::new(1, 1)),
+    (Vec2::new(-1, 0), Vec2::new(-1, 1)),
+    (Vec2::new(0, -1), Vec2::new(-1, -1)),
+];
+
+impl Metric {
+    fn sides(self) -> &'static Sides {
+        match self {
+            Metric::Manhattan => &MANHATTAN_SIDES,
+            Metric::Chebyshev => &CHEBYSHEV_SIDES,
+        }
+    }
+}
+
+/// Signed offset for types that may be unsigned.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Offset<T> {
+    neg: bool,
+    mag: T,
+}
+
+impl<T> Offset<T>
+where
+    T: Copy + Checked + One + Zero + PartialEq,
+{
+    fn new(dir: i8, amt: T) -> Self {
+        match dir {
+            0 => Self {
+                neg: false,
+                mag: T::ZERO,
+            },
+            d => Self {
+                neg: d < 0 && amt != T::ZERO,
+                mag: amt,
+            },
+        }
+    }
+
+    fn step(&mut self, dir: i8) -> Option<()> {
+        if dir == 0 {
+            return Some(());
+        }
+
+        if self.mag != T::ZERO && (dir > 0) == self.neg {
+            self.mag = self.mag.checked_sub(T::ONE)?;
+            if self.mag == T::ZERO {
+                self.neg = false;
+            }
+        } else {
+            self.mag = self.mag.checked_add(T::ONE)?;
+            self.neg = dir < 0;
+        }
+        Some(())
+    }
+
+    fn apply(&self, v: T) -> Option<T> {
+        if self.neg {
+            v.checked_sub(self.mag)
+        } else {
+            v.checked_add(self.mag)
+        }
+    }
+}
+
+/// Iterator over positions on 2D grid that are at the given distance from
+/// the center. Positions outside of the grid are skipped.
+///
+/// Positions are iterated clockwise starting at the top (at the top left
+/// corner with [`Metric::Chebyshev`]).
+#[derive(Debug, Clone)]
+pub struct Vec2RingIter<T> {
+    center: Vec2<T>,
+    bounds: Vec2<T>,
+    radius: T,
+    metric: Metric,
+    off: Vec2<Offset<T>>,
+    side: usize,
+}
+
+impl<T> Vec2RingIter<T>
+where
+    T: Copy + Checked + One + Zero + PartialEq,
+{
+    /// Create iterator over positions at distance `radius` from `center`
+    /// measured with the given `metric`. Only positions inside 2D space of
+    /// size `bounds` are produced. If stepping along the ring overflows `T`,
+    /// the iteration ends early.
+    pub fn new(
+        center: Vec2<T>,
+        radius: T,
+        metric: Metric,
+        bounds: Vec2<T>,
+    ) -> Self {
+        let start = metric.sides()[3].1;
+        Self {
+            center,
+            bounds,
+            radius,
+            metric,
+            off: start.map(|d| Offset::new(d, radius)),
+            side: 0,
+        }
+    }
+
+    fn advance(&mut self) {
+        if self.radius == T::ZERO {
+            self.side = 4;
+            return;
+        }
+
+        let (dir, corner) = self.metric.sides()[self.side];
+        if self.off.x.step(dir.x).is_none() || self.off.y.step(dir.y).is_none()
+        {
+            self.side = 4;
+            return;
+        }
+
+        if self.off == corner.map(|d| Offset::new(d, self.radius)) {
+            self.side += 1;
+        }
+    }
+}
+
+impl<T> Iterator for Vec2RingIter<T>
+where
+    T: Copy + Checked + One + Zero + PartialOrd,
+{
+    type Item = Vec2<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.side < 4 {
+            let pos = self
+                .off
+                .x
+                .apply(self.center.x)
+                .zip(self.off.y.apply(self.center.y));
+            self.advance();
+            if let Some(pos) = pos {
+                let pos = pos.into();
+                if self.bounds.size_contains(pos) {
+                    return Some(pos);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    /// Get all the positions in the given range sorted by y and then x.
+    fn cells(from: Vec2<i32>, to: Vec2<i32>) -> Vec<Vec2<i32>> {
+        let mut res = Vec::new();
+        for y in from.y..to.y {
+            for x in from.x..to.x {
+                res.push(Vec2::new(x, y));
+            }
+        }
+        res
+    }
+
+    fn dist(a: Vec2<i32>, b: Vec2<i32>, metric: Metric) -> i32 {
+        let d = (a - b).map(|a| a.abs());
+        match metric {
+            Metric::Manhattan => d.x + d.y,
+            Metric::Chebyshev => d.x.max(d.y),
+        }
+    }
+
+    #[test]
+    fn counts() {
+        let c = Vec2::new(50, 50);
+        for r in 1..=20 {
+            for (metric, cnt) in
+                [(Metric::Chebyshev, 8), (Metric::Manhattan, 4)]
+            {
+                let ring: Vec<_> = c.ring(r, metric, (100, 100)).collect();
+                assert_eq!(ring.len(), cnt * r as usize, "{metric:?} {r}");
+                assert!(ring.iter().all(|p| dist(*p, c, metric) == r));
+                let mut sorted = ring.clone();
+                sorted.sort_by_key(|p| (p.x, p.y));
+                sorted.dedup();
+                assert_eq!(sorted.len(), ring.len());
+            }
+        }
+        let ring: Vec<_> = c.ring(0, Metric::Chebyshev, (100, 100)).collect();
+        assert_eq!(ring, [c]);
+    }
+
+    #[test]
+    fn clockwise_from_top() {
+        let n: Vec<_> = Vec2::new(5, 5).neighbors4((10, 10)).collect();
+        assert_eq!(n, [(5, 4), (6, 5), (5, 6), (4, 5)].map(Vec2::from));
+        let n: Vec<_> = Vec2::new(5, 5).neighbors8((10, 10)).collect();
+        assert_eq!(
+            n,
+            [
+                (4, 4),
+                (5, 4),
+                (6, 4),
+                (6, 5),
+                (6, 6),
+                (5, 6),
+                (4, 6),
+                (4, 5)
+            ]
+            .map(Vec2::from)
+        );
+    }
+
+    #[test]
+    fn clipping() {
+        // Unsigned position at the origin.
+        let n: Vec<_> =
+            Vec2::<usize>::new(0, 0).neighbors8((10, 10)).collect();
+        assert_eq!(n, [(1, 0), (1, 1), (0, 1)].map(Vec2::from));
+        let n: Vec<_> = Vec2::<u8>::new(0, 0).neighbors4((10, 10)).collect();
+        assert_eq!(n, [(1, 0), (0, 1)].map(Vec2::from));
+
+        // At the far corner of the bounds.
+        let n: Vec<_> = Vec2::<u32>::new(9, 9).neighbors8((10, 10)).collect();
+        assert_eq!(n, [(8, 8), (9, 8), (8, 9)].map(Vec2::from));
+
+        // Positions that would overflow the type are skipped.
+        let c = Vec2::<u8>::new(250, 250);
+        let n: Vec<_> = c.ring(10, Metric::Chebyshev, (255, 255)).collect();
+        let expected = cells(Vec2::new(240, 240), Vec2::new(255, 255))
+            .into_iter()
+            .filter(|p| dist(*p, c.cast(), Metric::Chebyshev) == 10)
+            .count();
+        assert_eq!(n.len(), expected);
+        assert!(n.iter().all(|p| p.x == 240 || p.y == 240));
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let bounds = Vec2::new(7, 5);
+        for metric in [Metric::Manhattan, Metric::Chebyshev] {
+            for cy in 0..bounds.y {
+                for cx in 0..bounds.x {
+                    let c = Vec2::new(cx, cy);
+                    for r in 0..8 {
+                        let mut ring: Vec<Vec2<u32>> = c
+                            .cast::<u32>()
+                            .ring(r as u32, metric, bounds.cast::<u32>())
+                            .collect();
+                        ring.sort_by_key(|p| (p.y, p.x));
+                        let expected: Vec<Vec2<u32>> =
+                            cells(Vec2::ZERO, bounds)
+                                .into_iter()
+                                .filter(|p| dist(*p, c, metric) == r)
+                                .map(|p| p.cast())
+                                .collect();
+                        assert_eq!(ring, expected, "{metric:?} {c:?} {r}");
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use std::{
+use core::{
     cmp::Ordering,
     fmt::Display,
     mem,
@@ -6,18 +6,21 @@ use std::{
         Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg,
         Not, Rem, RemAssign, Sub, SubAssign,
     },
+    slice,
 };
 
 use crate::{
-    Checked, Float, Goniometric, IntoFloat, Isqrt, LargeType, MapExt,
-    NormalLimits, Scale, Sqrt, Vec2, Zero,
+    Checked, Goniometric, IntoFloat, Isqrt, LargeType, MapExt, NormalLimits,
+    Rad, Scale, Sqrt, Vec2, Zero,
+    curve::{compact3, compact3_wide, spread3, spread3_wide},
 };
 
 /// Represents three dimensional vector. Can be also use as color or any
 /// 3-tuple-like object where vector operations are benefit.
 ///
 /// It is ment to be as convinient as possible to work with in many use cases.
-#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
 pub struct Vec3<T = usize> {
     pub x: T,
     pub y: T,
@@ -29,6 +32,46 @@ impl<T> Vec3<T> {
         Self { x, y, z }
     }
 
+    /// Reinterpret slice of vectors as slice of their components.
+    pub fn as_slice(v: &[Self]) -> &[T] {
+        // SAFETY: `Vec3` is `repr(C)` with 3 fields of type `T`, so it has
+        // the same layout as `[T; 3]`.
+        unsafe { slice::from_raw_parts(v.as_ptr().cast(), v.len() * 3) }
+    }
+
+    /// Reinterpret mutable slice of vectors as slice of their components.
+    pub fn as_mut_slice(v: &mut [Self]) -> &mut [T] {
+        // SAFETY: `Vec3` is `repr(C)` with 3 fields of type `T`, so it has
+        // the same layout as `[T; 3]`.
+        unsafe {
+            slice::from_raw_parts_mut(v.as_mut_ptr().cast(), v.len() * 3)
+        }
+    }
+
+    /// Reinterpret slice of components as slice of vectors. Returns
+    /// [`None`] if the length of the slice is not multiple of 3.
+    pub fn from_slice(s: &[T]) -> Option<&[Self]> {
+        if s.len() % 3 != 0 {
+            return None;
+        }
+        // SAFETY: `Vec3` is `repr(C)` with 3 fields of type `T`, so it has
+        // the same layout as `[T; 3]`.
+        Some(unsafe { slice::from_raw_parts(s.as_ptr().cast(), s.len() / 3) })
+    }
+
+    /// Reinterpret mutable slice of components as slice of vectors. Returns
+    /// [`None`] if the length of the slice is not multiple of 3.
+    pub fn from_mut_slice(s: &mut [T]) -> Option<&mut [Self]> {
+        if s.len() % 3 != 0 {
+            return None;
+        }
+        // SAFETY: `Vec3` is `repr(C)` with 3 fields of type `T`, so it has
+        // the same layout as `[T; 3]`.
+        Some(unsafe {
+            slice::from_raw_parts_mut(s.as_mut_ptr().cast(), s.len() / 3)
+        })
+    }
+
     /// Converts vector reference to vector of reference.
     pub fn as_ref(&self) -> Vec3<&T> {
         (&self.x, &self.y, &self.z).into()
@@ -40,13 +83,13 @@ impl<T> Vec3<T> {
     }
 
     /// Iterate over the three components.
-    pub fn iter(&self) -> std::array::IntoIter<&T, 3> {
+    pub fn iter(&self) -> core::array::IntoIter<&T, 3> {
         let r: [_; 3] = self.as_ref().into();
         r.into_iter()
     }
 
     /// Get mutable iterator over the three components.
-    pub fn iter_mut(&mut self) -> std::array::IntoIter<&mut T, 3> {
+    pub fn iter_mut(&mut self) -> core::array::IntoIter<&mut T, 3> {
         let r: [_; 3] = self.as_mut().into();
         r.into_iter()
     }
@@ -589,6 +632,16 @@ impl<T> Vec3<T> {
         Vec2::from(self.plane_x()).angle()
     }
 
+    /// Calculate angle to the X axis as [`Rad`].
+    pub fn angle_x_rad(self) -> Rad<<T as Goniometric>::Output>
+    where
+        T: Copy + Mul + Goniometric,
+        <T as Mul>::Output: Add<<T as Mul>::Output>,
+        <<T as Mul>::Output as Add>::Output: Sqrt<Output = T>,
+    {
+        Rad(self.angle_x())
+    }
+
     /// Calculate angle to the Y axis.
     pub fn angle_y(self) -> <T as Goniometric>::Output
     where
@@ -599,6 +652,16 @@ impl<T> Vec3<T> {
         Vec2::from(self.plane_y()).angle()
     }
 
+    /// Calculate angle to the Y axis as [`Rad`].
+    pub fn angle_y_rad(self) -> Rad<<T as Goniometric>::Output>
+    where
+        T: Copy + Mul + Goniometric,
+        <T as Mul>::Output: Add<<T as Mul>::Output>,
+        <<T as Mul>::Output as Add>::Output: Sqrt<Output = T>,
+    {
+        Rad(self.angle_y())
+    }
+
     /// Calculate angle to the Z axis.
     pub fn angle_z(self) -> <T as Goniometric>::Output
     where
@@ -609,6 +672,16 @@ impl<T> Vec3<T> {
         Vec2::from(self.plane_z()).angle()
     }
 
+    /// Calculate angle to the Z axis as [`Rad`].
+    pub fn angle_z_rad(self) -> Rad<<T as Goniometric>::Output>
+    where
+        T: Copy + Mul + Goniometric,
+        <T as Mul>::Output: Add<<T as Mul>::Output>,
+        <<T as Mul>::Output as Add>::Output: Sqrt<Output = T>,
+    {
+        Rad(self.angle_z())
+    }
+
     /// Calculate polar coordinates with X being the polar axis.
     #[allow(clippy::type_complexity)]
     pub fn polar_x(
@@ -715,7 +788,7 @@ impl<T> Vec3<T> {
     /// Normalize the vector.
     pub fn normalize(&mut self)
     where
-        T: Copy + Float + Mul,
+        T: Copy + Mul,
         T::Output: Add,
         <T::Output as Add>::Output: Add<T::Output>,
         <<T::Output as Add>::Output as Add<T::Output>>::Output: Sqrt,
@@ -916,6 +989,36 @@ impl Vec3<bool> {
     }
 }
 
+impl Vec3<u16> {
+    /// Get the index of this position on 3D Morton curve (Z-order).
+    pub fn morton(self) -> u64 {
+        spread3(self.x) | (spread3(self.y) << 1) | (spread3(self.z) << 2)
+    }
+
+    /// Get the position at the given index on 3D Morton curve (Z-order).
+    pub fn from_morton(d: u64) -> Self {
+        Self::new(compact3(d), compact3(d >> 1), compact3(d >> 2))
+    }
+}
+
+impl Vec3<u32> {
+    /// Get the index of this position on 3D Morton curve (Z-order).
+    pub fn morton(self) -> u128 {
+        spread3_wide(self.x)
+            | (spread3_wide(self.y) << 1)
+            | (spread3_wide(self.z) << 2)
+    }
+
+    /// Get the position at the given index on 3D Morton curve (Z-order).
+    pub fn from_morton(d: u128) -> Self {
+        Self::new(
+            compact3_wide(d),
+            compact3_wide(d >> 1),
+            compact3_wide(d >> 2),
+        )
+    }
+}
+
 impl<T> Vec3<&T> {
     /// Clones the components.
     pub fn cloned(self) -> Vec3<T>
@@ -1046,14 +1149,14 @@ where
 }
 
 impl<T: Display> Display for Vec3<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "[{}, {}, {}]", self.x, self.y, self.z)
     }
 }
 
 impl<T> IntoIterator for Vec3<T> {
     type Item = T;
-    type IntoIter = std::array::IntoIter<T, 3>;
+    type IntoIter = core::array::IntoIter<T, 3>;
 
     fn into_iter(self) -> Self::IntoIter {
         let r: [_; 3] = self.into();
@@ -1063,7 +1166,7 @@ impl<T> IntoIterator for Vec3<T> {
 
 impl<'a, T> IntoIterator for &'a Vec3<T> {
     type Item = &'a T;
-    type IntoIter = std::array::IntoIter<&'a T, 3>;
+    type IntoIter = core::array::IntoIter<&'a T, 3>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
@@ -1072,7 +1175,7 @@ impl<'a, T> IntoIterator for &'a Vec3<T> {
 
 impl<'a, T> IntoIterator for &'a mut Vec3<T> {
     type Item = &'a mut T;
-    type IntoIter = std::array::IntoIter<&'a mut T, 3>;
+    type IntoIter = core::array::IntoIter<&'a mut T, 3>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.iter_mut()
@@ -1099,7 +1202,7 @@ where
 }
 
 impl<T: PartialOrd> PartialOrd for Vec3<T> {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         match (
             self.x.partial_cmp(&other.x)?,
             self.y.partial_cmp(&other.y)?,
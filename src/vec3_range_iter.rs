@@ -1,71 +1,208 @@
-use std::ops::AddAssign;
+use core::{
+    iter::FusedIterator,
+    ops::{Add, Div, Mul, Rem, Sub},
+};
 
-use crate::{One, Vec2, Vec3};
+use crate::{
+    Cast, CompArithm, MapExt, One, Vec3, Zero, vec2_range_iter::step_cnt,
+};
 
 /// Iterator over 3D volume.
+///
+/// By default the volume is iterated by x first, then y and z last.
+#[derive(Debug, Clone)]
 pub struct Vec3RangeIter<T> {
     start: Vec3<T>,
-    end: Vec3<T>,
-    xy: Vec2<T>,
+    step: Vec3<T>,
+    size: Vec3<usize>,
+    col_major: bool,
+    front: usize,
+    back: usize,
 }
 
-impl<T> Vec3RangeIter<T> {
+impl<T> Vec3RangeIter<T>
+where
+    T: Copy
+        + Sub<Output = T>
+        + Div<Output = T>
+        + Mul<Output = T>
+        + PartialOrd
+        + Cast<usize>,
+    usize: Cast<T>,
+{
     /// Create new iterator over 3D volume.
+    /// Panics if the number of positions doesn't fit into `usize`.
     pub fn new(start: Vec3<T>, end: Vec3<T>) -> Self
     where
-        T: Copy,
+        T: One,
+    {
+        Self::stepped(start, end, Vec3::new(T::ONE, T::ONE, T::ONE))
+    }
+
+    /// Create new iterator over 3D volume that moves with the given step.
+    /// Panics if any component of the step is not positive or if the number
+    /// of positions doesn't fit into `usize`.
+    pub fn with_step(start: Vec3<T>, end: Vec3<T>, step: Vec3<T>) -> Self
+    where
+        T: Zero,
     {
+        assert!(
+            step.x > T::ZERO && step.y > T::ZERO && step.z > T::ZERO,
+            "Step must be positive."
+        );
+        Self::stepped(start, end, step)
+    }
+
+    fn stepped(start: Vec3<T>, end: Vec3<T>, step: Vec3<T>) -> Self {
+        let size = Vec3::new(
+            step_cnt(start.x, end.x, step.x),
+            step_cnt(start.y, end.y, step.y),
+            step_cnt(start.z, end.z, step.z),
+        );
+        let len = size
+            .x
+            .checked_mul(size.y)
+            .and_then(|a| a.checked_mul(size.z))
+            .expect("Too many positions.");
         Self {
-            xy: start.xy(),
             start,
-            end,
+            step,
+            front: 0,
+            back: len,
+            size,
+            col_major: false,
         }
     }
+}
+
+impl<T> Vec3RangeIter<T> {
+    /// Iterate by z first, then y and x last. This should be called before
+    /// the iteration starts.
+    pub fn col_major(mut self) -> Self {
+        self.col_major = true;
+        self
+    }
+
+    fn idx_pos(&self, i: usize) -> Vec3<usize> {
+        let Vec3 { x, y, z } = self.size;
+        if self.col_major {
+            Vec3::new(i / (y * z), i / z % y, i % z)
+        } else {
+            Vec3::new(i % x, i / x % y, i / (x * y))
+        }
+    }
+
+    fn get(&self, i: usize) -> Vec3<T>
+    where
+        T: Copy + Add<Output = T> + Mul<Output = T>,
+        usize: Cast<T>,
+    {
+        self.start + self.idx_pos(i).cast().cmul(self.step)
+    }
 
     /// Check whether the remaining volume contains the given position.
     pub fn contains(&self, other: impl Into<Vec3<T>>) -> bool
     where
-        T: Ord,
+        T: Copy
+            + Sub<Output = T>
+            + Div<Output = T>
+            + Rem<Output = T>
+            + PartialOrd
+            + Zero
+            + Cast<usize>,
     {
-        let Vec3 { x, y, z } = other.into();
-        x >= self.start.x
-            && x < self.end.x
-            && y >= self.start.y
-            && y < self.end.y
-            && z >= self.start.z
-            && z < self.end.z
+        let pos = other.into();
+        if pos.x < self.start.x || pos.y < self.start.y || pos.z < self.start.z
+        {
+            return false;
+        }
+
+        let off = pos - self.start;
+        if off.crem(self.step).is_any(|a| *a != T::ZERO) {
+            return false;
+        }
+
+        let idx: Vec3<usize> = off.cdiv(self.step).cast();
+        let Vec3 { x, y, z } = self.size;
+        if idx.x >= x || idx.y >= y || idx.z >= z {
+            return false;
+        }
+
+        let i = if self.col_major {
+            (idx.x * y + idx.y) * z + idx.z
+        } else {
+            (idx.z * y + idx.y) * x + idx.x
+        };
+        (self.front..self.back).contains(&i)
     }
 }
 
 impl<T> Iterator for Vec3RangeIter<T>
 where
-    T: Copy + AddAssign + PartialOrd + One,
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+    usize: Cast<T>,
 {
     type Item = Vec3<T>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.xy.x >= self.end.x {
-            if self.xy.x == self.start.x {
-                return None;
-            }
-            self.xy.x = self.start.x;
-            self.xy.y += T::ONE;
+        if self.front >= self.back {
+            return None;
         }
 
-        if self.xy.y >= self.end.y {
-            if self.xy.y == self.start.y {
-                return None;
-            }
-            self.xy.y = self.start.y;
-            self.start.z += T::ONE;
-        }
+        let res = self.get(self.front);
+        self.front += 1;
+        Some(res)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
+    }
+}
 
-        if self.start.z > self.end.z {
+impl<T> DoubleEndedIterator for Vec3RangeIter<T>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+    usize: Cast<T>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
             return None;
         }
 
-        let res = (self.xy, self.start.z);
-        self.xy.x += T::ONE;
-        Some(res.into())
+        self.back -= 1;
+        Some(self.get(self.back))
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.back = self.back.saturating_sub(n).max(self.front);
+        self.next_back()
     }
 }
+
+impl<T> ExactSizeIterator for Vec3RangeIter<T>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+    usize: Cast<T>,
+{
+}
+
+impl<T> FusedIterator for Vec3RangeIter<T>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+    usize: Cast<T>,
+{
+}
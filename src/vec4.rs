@@ -1,16 +1,18 @@
-use std::{
+use core::{
     cmp::Ordering,
     fmt::Display,
     ops::{
         Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg,
         Not, Rem, RemAssign, Sub, SubAssign,
     },
+    slice,
 };
 
-use crate::{MapExt, RectExt, Vec2};
+use crate::{MapExt, RectExt, Sqrt, Vec2};
 
 /// Four dimensional vector or any 4-tuple-like object (e.g. rectangle).
-#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
 pub struct Vec4<T = usize> {
     pub x: T,
     pub y: T,
@@ -24,6 +26,46 @@ impl<T> Vec4<T> {
         Self { x, y, z, w }
     }
 
+    /// Reinterpret slice of vectors as slice of their components.
+    pub fn as_slice(v: &[Self]) -> &[T] {
+        // SAFETY: `Vec4` is `repr(C)` with 4 fields of type `T`, so it has
+        // the same layout as `[T; 4]`.
+        unsafe { slice::from_raw_parts(v.as_ptr().cast(), v.len() * 4) }
+    }
+
+    /// Reinterpret mutable slice of vectors as slice of their components.
+    pub fn as_mut_slice(v: &mut [Self]) -> &mut [T] {
+        // SAFETY: `Vec4` is `repr(C)` with 4 fields of type `T`, so it has
+        // the same layout as `[T; 4]`.
+        unsafe {
+            slice::from_raw_parts_mut(v.as_mut_ptr().cast(), v.len() * 4)
+        }
+    }
+
+    /// Reinterpret slice of components as slice of vectors. Returns
+    /// [`None`] if the length of the slice is not multiple of 4.
+    pub fn from_slice(s: &[T]) -> Option<&[Self]> {
+        if s.len() % 4 != 0 {
+            return None;
+        }
+        // SAFETY: `Vec4` is `repr(C)` with 4 fields of type `T`, so it has
+        // the same layout as `[T; 4]`.
+        Some(unsafe { slice::from_raw_parts(s.as_ptr().cast(), s.len() / 4) })
+    }
+
+    /// Reinterpret mutable slice of components as slice of vectors. Returns
+    /// [`None`] if the length of the slice is not multiple of 4.
+    pub fn from_mut_slice(s: &mut [T]) -> Option<&mut [Self]> {
+        if s.len() % 4 != 0 {
+            return None;
+        }
+        // SAFETY: `Vec4` is `repr(C)` with 4 fields of type `T`, so it has
+        // the same layout as `[T; 4]`.
+        Some(unsafe {
+            slice::from_raw_parts_mut(s.as_mut_ptr().cast(), s.len() / 4)
+        })
+    }
+
     /// Width of rectangle represented by this vector.
     pub fn width_mut(&mut self) -> &mut T {
         &mut self.z
@@ -75,14 +117,67 @@ impl<T> Vec4<T> {
         (&mut self.x, &mut self.y, &mut self.z, &mut self.w).into()
     }
 
+    /// Calculate the dot product of two 4D vectors.
+    #[allow(clippy::type_complexity)]
+    pub fn dot<Right>(
+        self,
+        other: impl Into<Vec4<Right>>,
+    ) -> <<<T::Output as Add>::Output as Add<T::Output>>::Output as Add<
+        T::Output,
+    >>::Output
+    where
+        T: Mul<Right>,
+        T::Output: Add,
+        <T::Output as Add>::Output: Add<T::Output>,
+        <<T::Output as Add>::Output as Add<T::Output>>::Output: Add<T::Output>,
+    {
+        let o = other.into();
+        self.x * o.x + self.y * o.y + self.z * o.z + self.w * o.w
+    }
+
+    /// Calculate the square of the length of the vector.
+    #[allow(clippy::type_complexity)]
+    pub fn sq_len(
+        &self,
+    ) -> <<<T::Output as Add>::Output as Add<T::Output>>::Output as Add<
+        T::Output,
+    >>::Output
+    where
+        T: Copy + Mul,
+        T::Output: Add,
+        <T::Output as Add>::Output: Add<T::Output>,
+        <<T::Output as Add>::Output as Add<T::Output>>::Output: Add<T::Output>,
+    {
+        self.dot(*self)
+    }
+
+    /// Calculate the length of the vector.
+    #[allow(clippy::type_complexity)]
+    pub fn len(
+        &self,
+    ) -> <<<<T::Output as Add>::Output as Add<T::Output>>::Output as Add<
+        T::Output,
+    >>::Output as Sqrt>::Output
+    where
+        T: Copy + Mul,
+        T::Output: Add,
+        <T::Output as Add>::Output: Add<T::Output>,
+        <<T::Output as Add>::Output as Add<T::Output>>::Output: Add<T::Output>,
+        <<<T::Output as Add>::Output as Add<T::Output>>::Output as Add<
+            T::Output,
+        >>::Output: Sqrt,
+    {
+        self.sq_len().sqrt()
+    }
+
     /// Iterate the vector.
-    pub fn iter(&self) -> std::array::IntoIter<&T, 4> {
+    pub fn iter(&self) -> core::array::IntoIter<&T, 4> {
         let r: [_; 4] = self.as_ref().into();
         r.into_iter()
     }
 
     /// Iterate the vector as mutable.
-    pub fn iter_mut(&mut self) -> std::array::IntoIter<&mut T, 4> {
+    pub fn iter_mut(&mut self) -> core::array::IntoIter<&mut T, 4> {
         let r: [_; 4] = self.as_mut().into();
         r.into_iter()
     }
@@ -216,14 +311,14 @@ where
 }
 
 impl<T: Display> Display for Vec4<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "[{}, {}, {}, {}]", self.x, self.y, self.z, self.w)
     }
 }
 
 impl<T> IntoIterator for Vec4<T> {
     type Item = T;
-    type IntoIter = std::array::IntoIter<T, 4>;
+    type IntoIter = core::array::IntoIter<T, 4>;
 
     fn into_iter(self) -> Self::IntoIter {
         let r: [_; 4] = self.into();
@@ -233,7 +328,7 @@ impl<T> IntoIterator for Vec4<T> {
 
 impl<'a, T> IntoIterator for &'a Vec4<T> {
     type Item = &'a T;
-    type IntoIter = std::array::IntoIter<&'a T, 4>;
+    type IntoIter = core::array::IntoIter<&'a T, 4>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
@@ -242,7 +337,7 @@ impl<'a, T> IntoIterator for &'a Vec4<T> {
 
 impl<'a, T> IntoIterator for &'a mut Vec4<T> {
     type Item = &'a mut T;
-    type IntoIter = std::array::IntoIter<&'a mut T, 4>;
+    type IntoIter = core::array::IntoIter<&'a mut T, 4>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.iter_mut()
@@ -269,7 +364,7 @@ where
 }
 
 impl<T: PartialOrd> PartialOrd for Vec4<T> {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         match (
             self.x.partial_cmp(&other.x)?,
             self.y.partial_cmp(&other.y)?,
@@ -326,6 +421,7 @@ macro_rules! op_assign_single {
                 self.x.$fn(rhs);
                 self.y.$fn(rhs);
                 self.z.$fn(rhs);
+                self.w.$fn(rhs);
             }
         }
     };